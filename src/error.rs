@@ -36,10 +36,7 @@ impl ValidationError {
 
     /// Check if it already contains certain error code
     pub fn contains(&self, error_code: &str) -> bool {
-        matches!(
-            self.errors.iter().position(|e| e.starts_with(error_code)),
-            Some(_)
-        )
+        self.errors.iter().any(|e| e.starts_with(error_code))
     }
 
     /// Check if the error is empty
@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Options for the base64 validation, standard padded alphabet by default
+#[derive(Clone, Debug)]
+pub struct Base64Options {
+    url_safe: bool,
+    padded: bool,
+    max_size: Option<usize>,
+}
+
+impl Default for Base64Options {
+    fn default() -> Self {
+        Base64Options {
+            url_safe: false,
+            padded: true,
+            max_size: None,
+        }
+    }
+}
+
+impl Base64Options {
+    /// Create options for the standard padded alphabet without size limit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the URL safe alphabet, `-` and `_` instead of `+` and `/`
+    pub fn url_safe(mut self, url_safe: bool) -> Self {
+        self.url_safe = url_safe;
+
+        self
+    }
+
+    /// Whether the value has to be padded with `=`, or must not be padded at all
+    pub fn padded(mut self, padded: bool) -> Self {
+        self.padded = padded;
+
+        self
+    }
+
+    /// Maximum number of decoded bytes
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+
+        self
+    }
+}
+
+/// Reason why the value did not pass the base64 validation
+#[derive(Clone, Debug, PartialEq)]
+pub enum Base64Error {
+    /// Value is not base64 encoded
+    Invalid,
+    /// Decoded value is larger than allowed, holds the allowed size
+    MaxSize(usize),
+}
+
+impl Base64Error {
+    /// Error code that is reported for the field
+    pub fn code(&self) -> String {
+        match self {
+            Base64Error::Invalid => "base64".to_string(),
+            Base64Error::MaxSize(max) => format!("base64_max_size:{}", max),
+        }
+    }
+}
+
+/// Decodes the base64 value, returns `None` if it's not encoded with the given alphabet
+/// and padding
+#[must_use]
+pub fn decode_base64<'a, T>(val: T, options: &Base64Options) -> Option<Vec<u8>>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let alphabet = if options.url_safe { URL_SAFE } else { STANDARD };
+
+    let data = if options.padded {
+        if val.len() % 4 != 0 {
+            return None;
+        }
+
+        let data = val.trim_end_matches('=');
+
+        if val.len() - data.len() > 2 {
+            return None;
+        }
+
+        data
+    } else {
+        val.as_ref()
+    };
+
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in data.bytes() {
+        let index = alphabet.iter().position(|a| *a == c)? as u32;
+
+        buffer = (buffer << 6) | index;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Validates the base64 value against the given alphabet, padding and size options
+pub fn validate_base64_with<'a, T>(val: T, options: &Base64Options) -> Result<(), Base64Error>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    // Every 4 encoded characters hold 3 bytes, so oversized values are rejected
+    // before they are decoded
+    if let Some(max) = options.max_size {
+        if val.trim_end_matches('=').len() * 3 / 4 > max {
+            return Err(Base64Error::MaxSize(max));
+        }
+    }
+
+    match decode_base64(val, options) {
+        Some(_) => Ok(()),
+        None => Err(Base64Error::Invalid),
+    }
+}
+
+/// Validates whether the given string is standard padded base64
+#[must_use]
+pub fn validate_base64<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    decode_base64(val, &Base64Options::new()).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{decode_base64, validate_base64, validate_base64_with, Base64Error, Base64Options};
+
+    #[test]
+    fn test_validate_base64() {
+        let tests = vec![
+            ("", true),
+            ("Zg==", true),
+            ("Zm8=", true),
+            ("Zm9v", true),
+            ("aGVsbG8gd29ybGQ=", true),
+            ("+/+/", true),
+            ("Zg", false),
+            ("Zg=", false),
+            ("Z===", false),
+            ("Zm9v!", false),
+            ("Zm=v", false),
+            ("-_-_", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_base64(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_base64_cow() {
+        let test: Cow<'static, str> = "Zm9v".into();
+        assert!(validate_base64(test));
+        let test: Cow<'static, str> = String::from("Zm9v").into();
+        assert!(validate_base64(test));
+        let test: Cow<'static, str> = "Zm9".into();
+        assert!(!validate_base64(test));
+        let test: Cow<'static, str> = String::from("Zm9").into();
+        assert!(!validate_base64(test));
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        let options = Base64Options::new();
+
+        assert_eq!(decode_base64("Zg==", &options), Some(b"f".to_vec()));
+        assert_eq!(decode_base64("Zm8=", &options), Some(b"fo".to_vec()));
+        assert_eq!(
+            decode_base64("aGVsbG8gd29ybGQ=", &options),
+            Some(b"hello world".to_vec())
+        );
+
+        let options = Base64Options::new().url_safe(true).padded(false);
+
+        assert_eq!(decode_base64("-_8", &options), Some(vec![0xfb, 0xff]));
+        assert_eq!(decode_base64("Zm8", &options), Some(b"fo".to_vec()));
+        assert_eq!(decode_base64("Zm8=", &options), None);
+        assert_eq!(decode_base64("+/8", &options), None);
+    }
+
+    #[test]
+    fn test_validate_base64_with() {
+        let options = Base64Options::new().max_size(5);
+
+        assert_eq!(validate_base64_with("aGVsbG8=", &options), Ok(()));
+        assert_eq!(
+            validate_base64_with("aGVsbG8gd29ybGQ=", &options),
+            Err(Base64Error::MaxSize(5))
+        );
+        assert_eq!(
+            validate_base64_with("aGVsbG8", &options),
+            Err(Base64Error::Invalid)
+        );
+        assert_eq!(
+            validate_base64_with("aGVsbA==", &Base64Options::new().max_size(4)),
+            Ok(())
+        );
+        assert_eq!(
+            validate_base64_with("aGVsbG8=", &Base64Options::new().max_size(4)),
+            Err(Base64Error::MaxSize(4))
+        );
+        assert_eq!(
+            validate_base64_with("A".repeat(1_000_000), &options),
+            Err(Base64Error::MaxSize(5))
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+
+lazy_static! {
+    // 4 letter bank code, 2 letter country code, 2 character location code
+    // and an optional 3 character branch code (ISO 9362)
+    static ref BIC_RE: Regex = Regex::new(r"^[A-Z]{4}[A-Z]{2}[A-Z0-9]{2}(?:[A-Z0-9]{3})?$").unwrap();
+}
+
+/// Validates whether the given string is a BIC/SWIFT code
+#[must_use]
+pub fn validate_bic<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    BIC_RE.is_match(&val.into().to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_bic;
+
+    #[test]
+    fn test_validate_bic() {
+        let tests = vec![
+            ("ZABAHR2X", true),
+            ("DEUTDEFF", true),
+            ("DEUTDEFF500", true),
+            ("deutdeff500", true),
+            ("NWBKGB2L", true),
+            ("DEUTDEFF50", false),
+            ("DEUTDEF", false),
+            ("DEU1DEFF", false),
+            ("DEUTD3FF", false),
+            ("DEUT DEFF", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_bic(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_bic_cow() {
+        let test: Cow<'static, str> = "ZABAHR2X".into();
+        assert!(validate_bic(test));
+        let test: Cow<'static, str> = String::from("ZABAHR2X").into();
+        assert!(validate_bic(test));
+        let test: Cow<'static, str> = "ZABAHR2".into();
+        assert!(!validate_bic(test));
+        let test: Cow<'static, str> = String::from("ZABAHR2").into();
+        assert!(!validate_bic(test));
+    }
+}
@@ -1,20 +1,161 @@
 use std::borrow::Cow;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use card_validate::Validate as CardValidate;
 
+pub use card_validate::Type as CardType;
+
+/// Reason why the card number did not pass the brand restricted validation
+#[derive(Clone, Debug, PartialEq)]
+pub enum CardError {
+    /// Card number is not valid
+    Invalid,
+    /// Card brand is not allowed, holds the detected brand
+    Brand(String),
+}
+
+impl CardError {
+    /// Error code that is reported for the field
+    pub fn code(&self) -> String {
+        match self {
+            CardError::Invalid => "credit_card".to_string(),
+            CardError::Brand(brand) => format!("credit_card_brand:{}", brand),
+        }
+    }
+}
+
+/// Returns the brand of the given card number, or `None` if the number is not valid
+#[must_use]
+pub fn credit_card_type<'a, T>(card: T) -> Option<CardType>
+where
+    T: Into<Cow<'a, str>>,
+{
+    CardValidate::from(&card.into()).ok().map(|v| v.card_type)
+}
+
 #[must_use]
 pub fn validate_credit_card<'a, T>(card: T) -> bool
 where
     T: Into<Cow<'a, str>>,
 {
-    CardValidate::from(&card.into()).is_ok()
+    credit_card_type(card).is_some()
+}
+
+/// Validates the card number and checks that its brand is one of the given brands
+pub fn validate_credit_card_brand<'a, T>(card: T, brands: &[CardType]) -> Result<(), CardError>
+where
+    T: Into<Cow<'a, str>>,
+{
+    match credit_card_type(card) {
+        Some(card_type) if brands.contains(&card_type) => Ok(()),
+        Some(card_type) => Err(CardError::Brand(card_type.name())),
+        None => Err(CardError::Invalid),
+    }
+}
+
+/// Validates whether the card expiry month and year are valid and not in the past.
+/// Two digit years are treated as years in the current century.
+#[must_use]
+pub fn validate_card_expiry<'a, T, V>(month: T, year: V) -> bool
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    let month = month.into();
+    let year = year.into();
+
+    if !month.chars().all(|c| c.is_ascii_digit()) || !year.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let (month, mut year) = match (month.parse::<u32>(), year.parse::<i64>()) {
+        (Ok(month), Ok(year)) if (1..=12).contains(&month) => (month, year),
+        _ => return false,
+    };
+
+    if year < 100 {
+        year += 2000;
+    }
+
+    (year, month) >= current_year_month()
+}
+
+/// Validates whether the given `MM/YY` or `MM/YYYY` card expiry is valid and not in the past
+#[must_use]
+pub fn validate_card_expiry_date<'a, T>(expiry: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    match expiry.into().split_once('/') {
+        Some((month, year)) if month.len() == 2 && (year.len() == 2 || year.len() == 4) => {
+            validate_card_expiry(month.trim(), year.trim())
+        }
+        _ => false,
+    }
+}
+
+/// Validates whether the CVV has the length required by the card brand,
+/// 4 digits for American Express and 3 digits for everything else.
+/// When the brand is not known both lengths are accepted.
+#[must_use]
+pub fn validate_cvv<'a, T>(cvv: T, card_type: Option<CardType>) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let cvv = cvv.into();
+
+    if !cvv.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    match card_type {
+        Some(CardType::Amex) => cvv.len() == 4,
+        Some(_) => cvv.len() == 3,
+        None => cvv.len() == 3 || cvv.len() == 4,
+    }
+}
+
+/// Removes spaces and dashes from the card number
+#[must_use]
+pub fn normalize_credit_card<'a, T>(card: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    card.into()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Current UTC year and month
+fn current_year_month() -> (i64, u32) {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 86_400)
+        .unwrap_or_default() as i64;
+
+    // Civil date from days since epoch, http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month)
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_credit_card;
+    use super::{
+        credit_card_type, current_year_month, normalize_credit_card, validate_card_expiry,
+        validate_card_expiry_date, validate_credit_card, validate_credit_card_brand, validate_cvv,
+        CardError, CardType,
+    };
 
     #[test]
     fn test_credit_card() {
@@ -33,12 +174,116 @@ mod tests {
     #[test]
     fn test_credit_card_cow() {
         let test: Cow<'static, str> = "4539571147647251".into();
-        assert_eq!(validate_credit_card(test), true);
+        assert!(validate_credit_card(test));
         let test: Cow<'static, str> = String::from("4539571147647251").into();
-        assert_eq!(validate_credit_card(test), true);
+        assert!(validate_credit_card(test));
         let test: Cow<'static, str> = "5236313877109141".into();
-        assert_eq!(validate_credit_card(test), false);
+        assert!(!validate_credit_card(test));
         let test: Cow<'static, str> = String::from("5236313877109141").into();
-        assert_eq!(validate_credit_card(test), false);
+        assert!(!validate_credit_card(test));
+    }
+
+    #[test]
+    fn test_credit_card_type() {
+        let tests = vec![
+            ("4539571147647251", Some(CardType::Visa)),
+            ("343380440754432", Some(CardType::Amex)),
+            ("5555555555554444", Some(CardType::MasterCard)),
+            ("5236313877109141", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(credit_card_type(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_credit_card_brand() {
+        let brands = [CardType::Visa, CardType::MasterCard];
+
+        let tests = vec![
+            ("4539571147647251", Ok(())),
+            ("5555555555554444", Ok(())),
+            ("343380440754432", Err(CardError::Brand("amex".to_string()))),
+            ("5236313877109141", Err(CardError::Invalid)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_credit_card_brand(input, &brands), expected);
+        }
+    }
+
+    #[test]
+    fn test_card_expiry() {
+        let (year, month) = current_year_month();
+
+        let tests = vec![
+            (month.to_string(), year.to_string(), true),
+            (format!("{:02}", month), (year % 100).to_string(), true),
+            ("1".to_string(), (year + 1).to_string(), true),
+            ("12".to_string(), (year - 1).to_string(), false),
+            ("13".to_string(), (year + 1).to_string(), false),
+            ("0".to_string(), (year + 1).to_string(), false),
+            ("+1".to_string(), (year + 1).to_string(), false),
+            ("aa".to_string(), "bb".to_string(), false),
+        ];
+
+        for (month, year, expected) in tests {
+            assert_eq!(
+                validate_card_expiry(month.as_str(), year.as_str()),
+                expected,
+                "{}/{}",
+                month,
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn test_card_expiry_date() {
+        let (year, _) = current_year_month();
+
+        let tests = vec![
+            (format!("01/{}", year + 1), true),
+            (format!("01/{}", (year + 1) % 100), true),
+            (format!("12/{}", year - 1), false),
+            (format!("1/{}", year + 1), false),
+            (format!("01{}", year + 1), false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                validate_card_expiry_date(input.as_str()),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_cvv() {
+        let tests = vec![
+            ("123", Some(CardType::Visa), true),
+            ("1234", Some(CardType::Visa), false),
+            ("1234", Some(CardType::Amex), true),
+            ("123", Some(CardType::Amex), false),
+            ("123", None, true),
+            ("1234", None, true),
+            ("12", None, false),
+            ("12a", None, false),
+        ];
+
+        for (input, card_type, expected) in tests {
+            assert_eq!(validate_cvv(input, card_type), expected);
+        }
+    }
+
+    #[test]
+    fn test_normalize_credit_card() {
+        assert_eq!(
+            normalize_credit_card("4539 5711-4764 7251"),
+            "4539571147647251".to_string()
+        );
     }
 }
@@ -0,0 +1,113 @@
+use heck::{ToKebabCase, ToLowerCamelCase, ToSnakeCase};
+use std::borrow::Cow;
+
+/// Uppercases the first letter of every word and lowercases the rest, e.g.
+/// `the QUICK brown-fox` becomes `The Quick Brown-Fox`. Whitespace and punctuation
+/// are kept, a letter following an apostrophe does not start a new word.
+#[must_use]
+pub fn title_case<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let mut result = String::with_capacity(val.len());
+    let mut word_start = true;
+
+    for c in val.chars() {
+        if word_start {
+            result.extend(c.to_uppercase());
+        } else {
+            result.extend(c.to_lowercase());
+        }
+
+        word_start = !c.is_alphanumeric() && c != '\'' && c != '’';
+    }
+
+    result
+}
+
+/// Converts the string to `snake_case`, e.g. `XMLHttpRequest` becomes `xml_http_request`
+#[must_use]
+pub fn snake_case<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().to_snake_case()
+}
+
+/// Converts the string to `camelCase`, e.g. `user_id` becomes `userId`
+#[must_use]
+pub fn camel_case<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().to_lower_camel_case()
+}
+
+/// Converts the string to `kebab-case`, e.g. `pageTitle` becomes `page-title`
+#[must_use]
+pub fn kebab_case<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().to_kebab_case()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{camel_case, kebab_case, snake_case, title_case};
+
+    #[test]
+    fn test_title_case() {
+        let tests = vec![
+            ("hello world", "Hello World"),
+            ("the QUICK brown-fox", "The Quick Brown-Fox"),
+            ("don't stop", "Don't Stop"),
+            ("  spaced   out ", "  Spaced   Out "),
+            ("čćžšđ ŠIFRA", "Čćžšđ Šifra"),
+            ("", ""),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(title_case(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_title_case_cow() {
+        let test: Cow<'static, str> = "hello world".into();
+        assert_eq!(title_case(test), "Hello World");
+        let test: Cow<'static, str> = String::from("hello world").into();
+        assert_eq!(title_case(test), "Hello World");
+    }
+
+    #[test]
+    fn test_case_conversion() {
+        let tests = vec![
+            // (input, snake, camel, kebab)
+            ("userId", "user_id", "userId", "user-id"),
+            ("User ID", "user_id", "userId", "user-id"),
+            ("page-title", "page_title", "pageTitle", "page-title"),
+            (
+                "XMLHttpRequest",
+                "xml_http_request",
+                "xmlHttpRequest",
+                "xml-http-request",
+            ),
+            (
+                "max_retry_count",
+                "max_retry_count",
+                "maxRetryCount",
+                "max-retry-count",
+            ),
+        ];
+
+        for (input, snake, camel, kebab) in tests {
+            assert_eq!(snake_case(input), snake, "{}", input);
+            assert_eq!(camel_case(input), camel, "{}", input);
+            assert_eq!(kebab_case(input), kebab, "{}", input);
+        }
+    }
+}
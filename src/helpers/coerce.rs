@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+/// Parses a boolean the way forms and query strings send it, ignoring the case and
+/// surrounding whitespace. `true`, `yes`, `y`, `on` and `1` are `true`, `false`, `no`,
+/// `n`, `off` and `0` are `false`, anything else is `None`.
+#[must_use]
+pub fn parse_bool<'a, T>(val: T) -> Option<bool>
+where
+    T: Into<Cow<'a, str>>,
+{
+    match val.into().trim().to_lowercase().as_str() {
+        "true" | "yes" | "y" | "on" | "1" => Some(true),
+        "false" | "no" | "n" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::parse_bool;
+
+    #[test]
+    fn test_parse_bool() {
+        let tests = vec![
+            ("true", Some(true)),
+            ("Yes", Some(true)),
+            ("y", Some(true)),
+            ("ON", Some(true)),
+            (" 1 ", Some(true)),
+            ("false", Some(false)),
+            ("no", Some(false)),
+            ("N", Some(false)),
+            ("off", Some(false)),
+            ("0", Some(false)),
+            ("", None),
+            ("2", None),
+            ("yep", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(parse_bool(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_bool_cow() {
+        let test: Cow<'static, str> = "on".into();
+        assert_eq!(parse_bool(test), Some(true));
+        let test: Cow<'static, str> = String::from("on").into();
+        assert_eq!(parse_bool(test), Some(true));
+        let test: Cow<'static, str> = "maybe".into();
+        assert_eq!(parse_bool(test), None);
+        let test: Cow<'static, str> = String::from("maybe").into();
+        assert_eq!(parse_bool(test), None);
+    }
+}
@@ -0,0 +1,110 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+
+lazy_static! {
+    static ref HEX_COLOR_RE: Regex =
+        Regex::new(r"^#(?:[0-9a-fA-F]{3,4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$").unwrap();
+    // rgb(255, 0, 0) and rgba(255, 0, 0, 0.5)
+    static ref RGB_LEGACY_RE: Regex = Regex::new(
+        r"(?i)^rgba?\(\s*([0-9.]+%?)\s*,\s*([0-9.]+%?)\s*,\s*([0-9.]+%?)\s*(?:,\s*([0-9.]+%?)\s*)?\)$"
+    )
+    .unwrap();
+    // rgb(255 0 0) and rgb(255 0 0 / 50%)
+    static ref RGB_RE: Regex = Regex::new(
+        r"(?i)^rgba?\(\s*([0-9.]+%?)\s+([0-9.]+%?)\s+([0-9.]+%?)\s*(?:/\s*([0-9.]+%?)\s*)?\)$"
+    )
+    .unwrap();
+}
+
+/// Parses a number or percentage and checks that it's within `0..=max`, or `0..=100%`
+fn in_range(val: &str, max: f64) -> bool {
+    let (number, max) = match val.strip_suffix('%') {
+        Some(number) => (number, 100.0),
+        None => (val, max),
+    };
+
+    number
+        .parse::<f64>()
+        .is_ok_and(|n| n.is_finite() && (0.0..=max).contains(&n))
+}
+
+/// Validates whether the given string is a hex or `rgb()` CSS color, e.g. `#fff`,
+/// `#ff000080`, `rgb(255, 0, 0)`, `rgba(255, 0, 0, 0.5)` or `rgb(255 0 0 / 50%)`
+#[must_use]
+pub fn validate_css_color<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let val = val.trim();
+
+    if HEX_COLOR_RE.is_match(val) {
+        return true;
+    }
+
+    let captures = match RGB_LEGACY_RE.captures(val).or_else(|| RGB_RE.captures(val)) {
+        Some(captures) => captures,
+        None => return false,
+    };
+
+    let channels = [&captures[1], &captures[2], &captures[3]];
+    let percentages = channels.iter().filter(|c| c.ends_with('%')).count();
+
+    // Channels have to be either all numbers or all percentages
+    (percentages == 0 || percentages == 3)
+        && channels.iter().all(|c| in_range(c, 255.0))
+        && captures
+            .get(4)
+            .map_or(true, |alpha| in_range(alpha.as_str(), 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_css_color;
+
+    #[test]
+    fn test_validate_css_color() {
+        let tests = vec![
+            ("#fff", true),
+            ("#FFFA", true),
+            ("#ff0000", true),
+            ("#ff000080", true),
+            ("rgb(255, 0, 0)", true),
+            ("RGB(255,0,0)", true),
+            ("rgba(255, 0, 0, 0.5)", true),
+            ("rgb(100%, 0%, 0%)", true),
+            ("rgb(255 0 0)", true),
+            ("rgb(255 0 0 / 50%)", true),
+            ("#ff", false),
+            ("#fffff", false),
+            ("#ggg", false),
+            ("fff", false),
+            ("rgb(256, 0, 0)", false),
+            ("rgb(255, 0)", false),
+            ("rgb(100%, 0, 0)", false),
+            ("rgba(255, 0, 0, 1.5)", false),
+            ("rgb(255, 0 0)", false),
+            ("red", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_css_color(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_css_color_cow() {
+        let test: Cow<'static, str> = "#fff".into();
+        assert!(validate_css_color(test));
+        let test: Cow<'static, str> = String::from("#fff").into();
+        assert!(validate_css_color(test));
+        let test: Cow<'static, str> = "#ff".into();
+        assert!(!validate_css_color(test));
+        let test: Cow<'static, str> = String::from("#ff").into();
+        assert!(!validate_css_color(test));
+    }
+}
@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+/// Validates whether the given string is an ISO 3166-1 alpha-2 country code, e.g. `HR`
+#[must_use]
+pub fn validate_country_code_alpha2<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    ALPHA_2_CODES.binary_search(&val.into().as_ref()).is_ok()
+}
+
+/// Validates whether the given string is an ISO 3166-1 alpha-3 country code, e.g. `HRV`
+#[must_use]
+pub fn validate_country_code_alpha3<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    ALPHA_3_CODES.binary_search(&val.into().as_ref()).is_ok()
+}
+
+/// Validates whether the given string is an ISO 3166-1 alpha-2 or alpha-3 country code
+#[must_use]
+pub fn validate_country_code<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    validate_country_code_alpha2(val.as_ref()) || validate_country_code_alpha3(val.as_ref())
+}
+
+/// ISO 3166-1 alpha-2 country codes
+const ALPHA_2_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// ISO 3166-1 alpha-3 country codes
+const ALPHA_3_CODES: &[&str] = &[
+    "ABW", "AFG", "AGO", "AIA", "ALA", "ALB", "AND", "ARE", "ARG", "ARM", "ASM", "ATA", "ATF",
+    "ATG", "AUS", "AUT", "AZE", "BDI", "BEL", "BEN", "BES", "BFA", "BGD", "BGR", "BHR", "BHS",
+    "BIH", "BLM", "BLR", "BLZ", "BMU", "BOL", "BRA", "BRB", "BRN", "BTN", "BVT", "BWA", "CAF",
+    "CAN", "CCK", "CHE", "CHL", "CHN", "CIV", "CMR", "COD", "COG", "COK", "COL", "COM", "CPV",
+    "CRI", "CUB", "CUW", "CXR", "CYM", "CYP", "CZE", "DEU", "DJI", "DMA", "DNK", "DOM", "DZA",
+    "ECU", "EGY", "ERI", "ESH", "ESP", "EST", "ETH", "FIN", "FJI", "FLK", "FRA", "FRO", "FSM",
+    "GAB", "GBR", "GEO", "GGY", "GHA", "GIB", "GIN", "GLP", "GMB", "GNB", "GNQ", "GRC", "GRD",
+    "GRL", "GTM", "GUF", "GUM", "GUY", "HKG", "HMD", "HND", "HRV", "HTI", "HUN", "IDN", "IMN",
+    "IND", "IOT", "IRL", "IRN", "IRQ", "ISL", "ISR", "ITA", "JAM", "JEY", "JOR", "JPN", "KAZ",
+    "KEN", "KGZ", "KHM", "KIR", "KNA", "KOR", "KWT", "LAO", "LBN", "LBR", "LBY", "LCA", "LIE",
+    "LKA", "LSO", "LTU", "LUX", "LVA", "MAC", "MAF", "MAR", "MCO", "MDA", "MDG", "MDV", "MEX",
+    "MHL", "MKD", "MLI", "MLT", "MMR", "MNE", "MNG", "MNP", "MOZ", "MRT", "MSR", "MTQ", "MUS",
+    "MWI", "MYS", "MYT", "NAM", "NCL", "NER", "NFK", "NGA", "NIC", "NIU", "NLD", "NOR", "NPL",
+    "NRU", "NZL", "OMN", "PAK", "PAN", "PCN", "PER", "PHL", "PLW", "PNG", "POL", "PRI", "PRK",
+    "PRT", "PRY", "PSE", "PYF", "QAT", "REU", "ROU", "RUS", "RWA", "SAU", "SDN", "SEN", "SGP",
+    "SGS", "SHN", "SJM", "SLB", "SLE", "SLV", "SMR", "SOM", "SPM", "SRB", "SSD", "STP", "SUR",
+    "SVK", "SVN", "SWE", "SWZ", "SXM", "SYC", "SYR", "TCA", "TCD", "TGO", "THA", "TJK", "TKL",
+    "TKM", "TLS", "TON", "TTO", "TUN", "TUR", "TUV", "TWN", "TZA", "UGA", "UKR", "UMI", "URY",
+    "USA", "UZB", "VAT", "VCT", "VEN", "VGB", "VIR", "VNM", "VUT", "WLF", "WSM", "YEM", "ZAF",
+    "ZMB", "ZWE",
+];
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        validate_country_code, validate_country_code_alpha2, validate_country_code_alpha3,
+        ALPHA_2_CODES, ALPHA_3_CODES,
+    };
+
+    #[test]
+    fn test_codes_are_sorted() {
+        assert!(ALPHA_2_CODES.windows(2).all(|w| w[0] < w[1]));
+        assert!(ALPHA_3_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_validate_country_code() {
+        let tests = vec![
+            ("HR", true),
+            ("HRV", true),
+            ("US", true),
+            ("USA", true),
+            ("hr", false),
+            ("XX", false),
+            ("HRVA", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_country_code(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_country_code_alpha() {
+        assert!(validate_country_code_alpha2("DE"));
+        assert!(!validate_country_code_alpha2("DEU"));
+        assert!(validate_country_code_alpha3("DEU"));
+        assert!(!validate_country_code_alpha3("DE"));
+    }
+
+    #[test]
+    fn test_validate_country_code_cow() {
+        let test: Cow<'static, str> = "HR".into();
+        assert!(validate_country_code(test));
+        let test: Cow<'static, str> = String::from("HR").into();
+        assert!(validate_country_code(test));
+        let test: Cow<'static, str> = "XX".into();
+        assert!(!validate_country_code(test));
+        let test: Cow<'static, str> = String::from("XX").into();
+        assert!(!validate_country_code(test));
+    }
+}
@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+/// Validates whether the given string is an ISO 4217 currency code, e.g. `EUR`
+#[must_use]
+pub fn validate_currency_code<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    CURRENCY_CODES.binary_search(&val.into().as_ref()).is_ok()
+}
+
+/// ISO 4217 currency codes, without the withdrawn ones
+const CURRENCY_CODES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BHD",
+    "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF",
+    "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP", "CVE", "CZK",
+    "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS",
+    "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD",
+    "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD",
+    "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT",
+    "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK",
+    "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD",
+    "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP",
+    "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS",
+    "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV", "WST",
+    "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR", "XOF", "XPD", "XPF",
+    "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_currency_code, CURRENCY_CODES};
+
+    #[test]
+    fn test_codes_are_sorted() {
+        assert!(CURRENCY_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_validate_currency_code() {
+        let tests = vec![
+            ("EUR", true),
+            ("USD", true),
+            ("JPY", true),
+            ("XCG", true),
+            ("ZWG", true),
+            ("HRK", false),
+            ("ZWL", false),
+            ("eur", false),
+            ("EU", false),
+            ("ABC", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_currency_code(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_currency_code_cow() {
+        let test: Cow<'static, str> = "EUR".into();
+        assert!(validate_currency_code(test));
+        let test: Cow<'static, str> = String::from("EUR").into();
+        assert!(validate_currency_code(test));
+        let test: Cow<'static, str> = "ABC".into();
+        assert!(!validate_currency_code(test));
+        let test: Cow<'static, str> = String::from("ABC").into();
+        assert!(!validate_currency_code(test));
+    }
+}
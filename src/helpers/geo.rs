@@ -0,0 +1,265 @@
+use std::borrow::Cow;
+
+/// Rectangular area given by its south west and north east corners.
+///
+/// When the west longitude is greater than the east one the box crosses the antimeridian.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_lng: f64,
+    pub max_lat: f64,
+    pub max_lng: f64,
+}
+
+impl BoundingBox {
+    /// Create the bounding box from its south west and north east corners
+    pub fn new(min_lat: f64, min_lng: f64, max_lat: f64, max_lng: f64) -> Self {
+        BoundingBox {
+            min_lat,
+            min_lng,
+            max_lat,
+            max_lng,
+        }
+    }
+
+    /// Checks whether the coordinate is inside the bounding box, edges included
+    #[must_use]
+    pub fn contains(&self, lat: f64, lng: f64) -> bool {
+        if !validate_latitude(lat) || !validate_longitude(lng) {
+            return false;
+        }
+
+        let lng_inside = if self.min_lng <= self.max_lng {
+            lng >= self.min_lng && lng <= self.max_lng
+        } else {
+            lng >= self.min_lng || lng <= self.max_lng
+        };
+
+        lat >= self.min_lat && lat <= self.max_lat && lng_inside
+    }
+}
+
+/// Validates whether the given number is a latitude, between -90 and 90 degrees
+#[must_use]
+pub fn validate_latitude(lat: f64) -> bool {
+    lat.is_finite() && (-90.0..=90.0).contains(&lat)
+}
+
+/// Validates whether the given number is a longitude, between -180 and 180 degrees
+#[must_use]
+pub fn validate_longitude(lng: f64) -> bool {
+    lng.is_finite() && (-180.0..=180.0).contains(&lng)
+}
+
+/// Parses a decimal degree coordinate, e.g. `45.81`, returns `None` for anything
+/// that is not a finite number
+#[must_use]
+pub fn parse_coordinate<'a, T>(val: T) -> Option<f64>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let val = val.trim();
+
+    // Rust parses `inf`, `NaN` and `1e5` as well, coordinates are plain decimals
+    if val.is_empty()
+        || !val
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == '-' || c == '+')
+    {
+        return None;
+    }
+
+    val.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// Parses a `"lat,lng"` string, e.g. `"45.81,15.98"`, into latitude and longitude.
+/// Returns `None` if the string is malformed or the coordinates are out of range.
+#[must_use]
+pub fn parse_lat_lng<'a, T>(val: T) -> Option<(f64, f64)>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let (lat, lng) = val.split_once(',')?;
+    let (lat, lng) = (parse_coordinate(lat)?, parse_coordinate(lng)?);
+
+    if validate_latitude(lat) && validate_longitude(lng) {
+        Some((lat, lng))
+    } else {
+        None
+    }
+}
+
+/// Validates whether the given string is a `"lat,lng"` pair, e.g. `"45.81,15.98"`
+#[must_use]
+pub fn validate_lat_lng<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_lat_lng(val).is_some()
+}
+
+/// Parses a GeoJSON point, e.g. `{"type": "Point", "coordinates": [15.98, 45.81]}`,
+/// into latitude and longitude. GeoJSON lists the longitude first, an optional
+/// altitude is allowed.
+#[must_use]
+pub fn parse_geojson_point<'a, T>(val: T) -> Option<(f64, f64)>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let value: serde_json::Value = serde_json::from_str(&val.into()).ok()?;
+
+    if value.get("type")?.as_str()? != "Point" {
+        return None;
+    }
+
+    let coordinates = value.get("coordinates")?.as_array()?;
+
+    if coordinates.len() != 2 && coordinates.len() != 3 {
+        return None;
+    }
+
+    let coordinates = coordinates
+        .iter()
+        .map(|c| c.as_f64())
+        .collect::<Option<Vec<f64>>>()?;
+    let (lng, lat) = (coordinates[0], coordinates[1]);
+
+    if validate_latitude(lat) && validate_longitude(lng) {
+        Some((lat, lng))
+    } else {
+        None
+    }
+}
+
+/// Validates whether the given string is a GeoJSON point with valid coordinates
+#[must_use]
+pub fn validate_geojson_point<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_geojson_point(val).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        parse_coordinate, parse_geojson_point, validate_geojson_point, validate_lat_lng,
+        validate_latitude, validate_longitude, BoundingBox,
+    };
+
+    #[test]
+    fn test_validate_latitude_longitude() {
+        assert!(validate_latitude(45.81));
+        assert!(validate_latitude(-90.0));
+        assert!(!validate_latitude(90.01));
+        assert!(!validate_latitude(f64::NAN));
+        assert!(validate_longitude(-180.0));
+        assert!(validate_longitude(15.98));
+        assert!(!validate_longitude(180.5));
+        assert!(!validate_longitude(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_parse_coordinate() {
+        let tests = vec![
+            ("45.81", Some(45.81)),
+            (" -15.5 ", Some(-15.5)),
+            ("+3", Some(3.0)),
+            ("1e5", None),
+            ("inf", None),
+            ("NaN", None),
+            ("", None),
+            ("4.5.1", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(parse_coordinate(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_lat_lng() {
+        let tests = vec![
+            ("45.81,15.98", true),
+            ("45.81, 15.98", true),
+            ("-90,-180", true),
+            ("90,180", true),
+            ("91,15.98", false),
+            ("45.81,181", false),
+            ("45.81", false),
+            ("45.81,15.98,3", false),
+            ("lat,lng", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_lat_lng(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_lat_lng_cow() {
+        let test: Cow<'static, str> = "45.81,15.98".into();
+        assert!(validate_lat_lng(test));
+        let test: Cow<'static, str> = String::from("45.81,15.98").into();
+        assert!(validate_lat_lng(test));
+        let test: Cow<'static, str> = "95.81,15.98".into();
+        assert!(!validate_lat_lng(test));
+        let test: Cow<'static, str> = String::from("95.81,15.98").into();
+        assert!(!validate_lat_lng(test));
+    }
+
+    #[test]
+    fn test_validate_geojson_point() {
+        let tests = vec![
+            (r#"{"type": "Point", "coordinates": [15.98, 45.81]}"#, true),
+            (
+                r#"{"type": "Point", "coordinates": [15.98, 45.81, 120]}"#,
+                true,
+            ),
+            (r#"{"type": "Point", "coordinates": [45.81, 95.98]}"#, false),
+            (r#"{"type": "Point", "coordinates": [15.98]}"#, false),
+            (
+                r#"{"type": "Point", "coordinates": ["15.98", "45.81"]}"#,
+                false,
+            ),
+            (
+                r#"{"type": "LineString", "coordinates": [[1, 2], [3, 4]]}"#,
+                false,
+            ),
+            (r#"{"coordinates": [15.98, 45.81]}"#, false),
+            ("45.81,15.98", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_geojson_point(input), expected, "{}", input);
+        }
+
+        assert_eq!(
+            parse_geojson_point(r#"{"type": "Point", "coordinates": [15.98, 45.81]}"#),
+            Some((45.81, 15.98))
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        // Croatia
+        let bbox = BoundingBox::new(42.39, 13.49, 46.55, 19.45);
+
+        assert!(bbox.contains(45.81, 15.98));
+        assert!(bbox.contains(42.39, 13.49));
+        assert!(!bbox.contains(48.21, 16.37));
+        assert!(!bbox.contains(f64::NAN, 15.98));
+
+        // Fiji, crosses the antimeridian
+        let bbox = BoundingBox::new(-21.0, 176.0, -12.0, -178.0);
+
+        assert!(bbox.contains(-18.14, 178.44));
+        assert!(bbox.contains(-16.5, -179.5));
+        assert!(!bbox.contains(-18.14, 170.0));
+    }
+}
@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+/// Validates whether the given string is hex encoded, an even number of hex digits
+#[must_use]
+pub fn validate_hex<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    !val.is_empty() && val.len() % 2 == 0 && val.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Validates whether the given string is hex encoded value of exactly the given
+/// number of bytes, e.g. 32 for a SHA-256 hash
+#[must_use]
+pub fn validate_hex_bytes<'a, T>(val: T, bytes: usize) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    val.len() == bytes * 2 && validate_hex(val)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{validate_hex, validate_hex_bytes};
+
+    #[test]
+    fn test_validate_hex() {
+        let tests = vec![
+            ("00", true),
+            ("deadBEEF", true),
+            ("d41d8cd98f00b204e9800998ecf8427e", true),
+            ("", false),
+            ("abc", false),
+            ("0x00", false),
+            ("zz", false),
+            ("00 11", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_hex(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_hex_cow() {
+        let test: Cow<'static, str> = "deadbeef".into();
+        assert!(validate_hex(test));
+        let test: Cow<'static, str> = String::from("deadbeef").into();
+        assert!(validate_hex(test));
+        let test: Cow<'static, str> = "deadbeefs".into();
+        assert!(!validate_hex(test));
+        let test: Cow<'static, str> = String::from("deadbeefs").into();
+        assert!(!validate_hex(test));
+    }
+
+    #[test]
+    fn test_validate_hex_bytes() {
+        let tests = vec![
+            ("d41d8cd98f00b204e9800998ecf8427e", 16, true),
+            ("d41d8cd98f00b204e9800998ecf8427e", 32, false),
+            ("deadbeef", 4, true),
+            ("deadbeeg", 4, false),
+        ];
+
+        for (input, bytes, expected) in tests {
+            assert_eq!(validate_hex_bytes(input, bytes), expected, "{}", input);
+        }
+    }
+}
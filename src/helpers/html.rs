@@ -0,0 +1,350 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+/// Elements whose content is not markup, they are always removed together with the content
+const RAW_TEXT_TAGS: [&str; 10] = [
+    "script",
+    "style",
+    "textarea",
+    "title",
+    "xmp",
+    "iframe",
+    "noembed",
+    "noframes",
+    "noscript",
+    "plaintext",
+];
+
+/// Tags and attributes that are kept when sanitizing HTML, everything else is removed.
+/// Links may only use the allowed URL schemes, `http`, `https` and `mailto` by default.
+#[derive(Clone, Debug)]
+pub struct HtmlPolicy {
+    tags: HashSet<String>,
+    attributes: HashMap<String, HashSet<String>>,
+    url_schemes: HashSet<String>,
+}
+
+impl Default for HtmlPolicy {
+    fn default() -> Self {
+        HtmlPolicy {
+            tags: HashSet::new(),
+            attributes: HashMap::new(),
+            url_schemes: ["http", "https", "mailto"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl HtmlPolicy {
+    /// Create policy that doesn't allow any tags
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy for basic rich text: paragraphs, line breaks, emphasis, lists, quotes, code
+    /// and links with `href` and `title`
+    pub fn basic() -> Self {
+        Self::new()
+            .tags(&[
+                "a",
+                "b",
+                "blockquote",
+                "br",
+                "code",
+                "em",
+                "i",
+                "li",
+                "ol",
+                "p",
+                "pre",
+                "strong",
+                "u",
+                "ul",
+            ])
+            .attributes("a", &["href", "title"])
+    }
+
+    /// Allow the tags, without any attributes
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.tags
+            .extend(tags.iter().map(|tag| tag.to_ascii_lowercase()));
+
+        self
+    }
+
+    /// Allow the attributes on the tag, the tag itself is allowed as well
+    pub fn attributes(mut self, tag: &str, attributes: &[&str]) -> Self {
+        let tag = tag.to_ascii_lowercase();
+
+        self.attributes
+            .entry(tag.clone())
+            .or_default()
+            .extend(attributes.iter().map(|a| a.to_ascii_lowercase()));
+        self.tags.insert(tag);
+
+        self
+    }
+
+    /// Replace the URL schemes allowed in `href`, `src` and other URL attributes,
+    /// relative URLs are always allowed
+    pub fn url_schemes(mut self, schemes: &[&str]) -> Self {
+        self.url_schemes = schemes.iter().map(|s| s.to_ascii_lowercase()).collect();
+
+        self
+    }
+
+    /// Ammonia builder that keeps only what the policy allows, content of `script`, `style`
+    /// and other elements that don't hold text is removed together with the element
+    fn builder(&self) -> ammonia::Builder<'_> {
+        let mut builder = ammonia::Builder::empty();
+
+        builder
+            .tags(
+                self.tags
+                    .iter()
+                    .map(String::as_str)
+                    .filter(|tag| !RAW_TEXT_TAGS.contains(tag))
+                    .collect(),
+            )
+            .clean_content_tags(RAW_TEXT_TAGS.iter().copied().collect())
+            .tag_attributes(
+                self.attributes
+                    .iter()
+                    .map(|(tag, attributes)| {
+                        (
+                            tag.as_str(),
+                            attributes.iter().map(String::as_str).collect(),
+                        )
+                    })
+                    .collect(),
+            )
+            .generic_attributes(HashSet::new())
+            .url_schemes(self.url_schemes.iter().map(String::as_str).collect())
+            .url_relative(ammonia::UrlRelative::PassThrough)
+            .link_rel(None)
+            .strip_comments(true);
+
+        builder
+    }
+}
+
+/// Escapes the characters that have a meaning in HTML: `&`, `<`, `>`, `"` and `'`
+#[must_use]
+pub fn escape_html<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let mut result = String::with_capacity(val.len());
+
+    for c in val.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#x27;"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Removes the tags and comments and keeps the text. Content of `script`, `style`
+/// and other elements that don't hold text is removed as well. Entities in the text
+/// are decoded, e.g. `Tom &amp; Jerry` becomes `Tom & Jerry`.
+#[must_use]
+pub fn strip_tags<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    unescape_text(&sanitize_html(val, &HtmlPolicy::new()))
+}
+
+/// Decodes the entities the serializer writes in text, `&amp;` last so that
+/// `&amp;lt;` stays `&lt;`
+fn unescape_text(val: &str) -> String {
+    val.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", "\u{A0}")
+        .replace("&amp;", "&")
+}
+
+/// Removes everything that is not allowed by the policy. The HTML is parsed the way
+/// browsers do it and written out again, so unclosed tags are closed, stray closing
+/// tags removed and `<` and `>` in the text are escaped.
+#[must_use]
+pub fn sanitize_html<'a, T>(val: T, policy: &HtmlPolicy) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    policy.builder().clean(&val.into()).to_string()
+}
+
+/// Validates whether the string is plain text without any tags or comments,
+/// e.g. `1 < 2` is valid but `<b>bold</b>` is not
+#[must_use]
+pub fn validate_no_html<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let mut rest = val.as_ref();
+
+    // A tag, end tag, comment or doctype starts with `<` followed by one of these,
+    // `<` before anything else is text
+    while let Some(i) = rest.find('<') {
+        rest = &rest[i + 1..];
+
+        let mut chars = rest.chars();
+
+        match chars.next() {
+            Some('!') | Some('?') => return false,
+            Some('/') if chars.next().is_some_and(|c| c.is_ascii_alphabetic()) => return false,
+            Some(c) if c.is_ascii_alphabetic() => return false,
+            _ => (),
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{escape_html, sanitize_html, strip_tags, validate_no_html, HtmlPolicy};
+
+    #[test]
+    fn test_sanitize_html() {
+        let policy = HtmlPolicy::basic();
+
+        let tests = vec![
+            ("<p>Hello <b>world</b></p>", "<p>Hello <b>world</b></p>"),
+            ("<P CLASS=\"x\">Hi</P>", "<p>Hi</p>"),
+            ("<p onclick='alert(1)'>Hi</p>", "<p>Hi</p>"),
+            ("<script>alert('<b>x</b>')</script>ok", "ok"),
+            ("<style>p { color: red }</style>ok", "ok"),
+            ("<div><span>kept text</span></div>", "kept text"),
+            ("<!-- comment -->text", "text"),
+            ("<p>unclosed <b>bold", "<p>unclosed <b>bold</b></p>"),
+            ("stray</b> close", "stray close"),
+            ("<b><i>overlap</b></i>", "<b><i>overlap</i></b>"),
+            ("1 < 2 > 0", "1 &lt; 2 &gt; 0"),
+            ("line<br/>break<br>", "line<br>break<br>"),
+            ("<b title=\"x\">t</b>", "<b>t</b>"),
+            ("<img src=x onerror=alert(1)>", ""),
+            ("<p>cut <a href=\"x", "<p>cut </p>"),
+            (
+                "<a href=\"https://example.com/?a=1&amp;b=2\" title='\"hi\"'>link</a>",
+                "<a href=\"https://example.com/?a=1&amp;b=2\" title=\"&quot;hi&quot;\">link</a>",
+            ),
+            ("<a href=\"/relative\">x</a>", "<a href=\"/relative\">x</a>"),
+            ("<a href=\"javascript:alert(1)\">x</a>", "<a>x</a>"),
+            ("<a href=\" JaVa\tScRiPt:alert(1)\">x</a>", "<a>x</a>"),
+            ("<a href=\"&#106;avascript:alert(1)\">x</a>", "<a>x</a>"),
+            ("<a href=\"&#x6A;avascript:alert(1)\">x</a>", "<a>x</a>"),
+            ("<a href=\"&#106avascript:alert(1)\">x</a>", "<a>x</a>"),
+            ("<a href=\"javascript&colon;alert(1)\">x</a>", "<a>x</a>"),
+            (
+                "<a href=\"javascript&unknown;alert(1)\">x</a>",
+                "<a href=\"javascript&amp;unknown;alert(1)\">x</a>",
+            ),
+            ("<a href=\"data:text/html,x\">x</a>", "<a>x</a>"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(sanitize_html(input, &policy), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_sanitize_html_policy() {
+        let policy = HtmlPolicy::new()
+            .tags(&["b"])
+            .attributes("img", &["src", "alt"])
+            .url_schemes(&["https"]);
+
+        assert_eq!(
+            sanitize_html("<p><b>x</b><i>y</i></p>", &policy),
+            "<b>x</b>y"
+        );
+        assert_eq!(
+            sanitize_html("<img src=\"https://x.io/a.png\" alt=a>", &policy),
+            "<img src=\"https://x.io/a.png\" alt=\"a\">"
+        );
+        assert_eq!(
+            sanitize_html("<img src=\"http://x.io/a.png\">", &policy),
+            "<img>"
+        );
+        assert_eq!(
+            sanitize_html("<script>x</script>", &HtmlPolicy::new().tags(&["script"])),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_sanitize_html_cow() {
+        let policy = HtmlPolicy::basic();
+
+        let test: Cow<'static, str> = "<b onclick=x>hi</b>".into();
+        assert_eq!(sanitize_html(test, &policy), "<b>hi</b>");
+        let test: Cow<'static, str> = String::from("<b onclick=x>hi</b>").into();
+        assert_eq!(sanitize_html(test, &policy), "<b>hi</b>");
+    }
+
+    #[test]
+    fn test_strip_tags() {
+        let tests = vec![
+            ("<p>Hello <b>world</b></p>", "Hello world"),
+            ("<script>alert(1)</script>text", "text"),
+            ("a <!-- b --> c", "a  c"),
+            ("1 < 2 &amp; 3 > 2", "1 < 2 & 3 > 2"),
+            ("1 < 2 & 3", "1 < 2 & 3"),
+            ("Tom &amp; Jerry &lt;3", "Tom & Jerry <3"),
+            ("&amp;lt;", "&lt;"),
+            ("a&nbsp;b", "a\u{A0}b"),
+            ("<a href='x>y'>link</a>", "link"),
+            ("plain", "plain"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(strip_tags(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/a&gt;"
+        );
+        assert_eq!(escape_html("plain"), "plain");
+    }
+
+    #[test]
+    fn test_validate_no_html() {
+        let tests = vec![
+            ("plain text", true),
+            ("1 < 2 and 3 > 2", true),
+            ("a <3 b", true),
+            ("Tom &amp; Jerry", true),
+            ("<b>bold</b>", false),
+            ("text</p>", false),
+            ("<!-- comment -->", false),
+            ("<!DOCTYPE html>", false),
+            ("<img src=x onerror=alert(1)", false),
+            ("<script>", false),
+            ("<?xml version=\"1.0\"?>", false),
+            ("a </ b", true),
+            ("ends with <", true),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_no_html(input), expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+
+/// IBAN lengths per country from the SWIFT IBAN registry
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BI", 27),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DJ", 27),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FK", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HN", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("LY", 25),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MN", 20),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NI", 28),
+    ("NL", 18),
+    ("NO", 15),
+    ("OM", 23),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("RU", 33),
+    ("SA", 24),
+    ("SC", 31),
+    ("SD", 18),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("SO", 23),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+    ("YE", 30),
+];
+
+/// Validates whether the given string is an IBAN, checking the country specific
+/// length and the mod-97 checksum. Spaces and lowercase letters are tolerated.
+#[must_use]
+pub fn validate_iban<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let iban = normalize_iban(val);
+
+    if !iban.chars().all(|c| c.is_ascii_alphanumeric()) || iban.len() < 4 {
+        return false;
+    }
+
+    let (country, check_digits) = (&iban[0..2], &iban[2..4]);
+
+    if !check_digits.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    match IBAN_LENGTHS.iter().find(|(c, _)| *c == country) {
+        Some((_, length)) if *length == iban.len() => (),
+        _ => return false,
+    }
+
+    let remainder = iban[4..]
+        .chars()
+        .chain(iban[0..4].chars())
+        .fold(0u32, |acc, c| match c.to_digit(36) {
+            Some(d) if d >= 10 => (acc * 100 + d) % 97,
+            Some(d) => (acc * 10 + d) % 97,
+            None => acc,
+        });
+
+    remainder == 1
+}
+
+/// Removes spaces from the IBAN and turns it to uppercase
+#[must_use]
+pub fn normalize_iban<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{normalize_iban, validate_iban};
+
+    #[test]
+    fn test_validate_iban() {
+        let tests = vec![
+            ("HR1210010051863000160", true),
+            ("HR12 1001 0051 8630 0016 0", true),
+            ("DE89370400440532013000", true),
+            ("de89 3704 0044 0532 0130 00", true),
+            ("GB29NWBK60161331926819", true),
+            ("NO9386011117947", true),
+            ("MT84MALT011000012345MTLCAST001S", true),
+            ("HR1210010051863000161", false),
+            ("HR121001005186300016", false),
+            ("DE8937040044053201300", false),
+            ("XX89370400440532013000", false),
+            ("DEAB370400440532013000", false),
+            ("DE89-3704-0044-0532-0130-00", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_iban(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_iban_cow() {
+        let test: Cow<'static, str> = "DE89370400440532013000".into();
+        assert!(validate_iban(test));
+        let test: Cow<'static, str> = String::from("DE89370400440532013000").into();
+        assert!(validate_iban(test));
+        let test: Cow<'static, str> = "DE89370400440532013001".into();
+        assert!(!validate_iban(test));
+        let test: Cow<'static, str> = String::from("DE89370400440532013001").into();
+        assert!(!validate_iban(test));
+    }
+
+    #[test]
+    fn test_normalize_iban() {
+        assert_eq!(
+            normalize_iban("hr12 1001 0051 8630 0016 0"),
+            "HR1210010051863000160".to_string()
+        );
+    }
+}
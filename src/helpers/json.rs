@@ -0,0 +1,83 @@
+use serde::de::{DeserializeOwned, IgnoredAny};
+use std::borrow::Cow;
+
+/// Validates whether the given string is a JSON document
+#[must_use]
+pub fn validate_json<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    serde_json::from_str::<IgnoredAny>(&val.into()).is_ok()
+}
+
+/// Validates whether the given string is a JSON document that deserializes into `D`
+#[must_use]
+pub fn validate_json_as<'a, D, T>(val: T) -> bool
+where
+    D: DeserializeOwned,
+    T: Into<Cow<'a, str>>,
+{
+    serde_json::from_str::<D>(&val.into()).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::{validate_json, validate_json_as};
+
+    #[test]
+    fn test_validate_json() {
+        let tests = vec![
+            ("{}", true),
+            ("[1, 2, 3]", true),
+            (r#"{"name": "validr", "tags": ["a", "b"]}"#, true),
+            ("\"text\"", true),
+            ("42", true),
+            ("null", true),
+            ("", false),
+            ("{", false),
+            ("{'name': 'validr'}", false),
+            ("[1, 2,]", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_json(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_json_cow() {
+        let test: Cow<'static, str> = "{}".into();
+        assert!(validate_json(test));
+        let test: Cow<'static, str> = String::from("{}").into();
+        assert!(validate_json(test));
+        let test: Cow<'static, str> = "{".into();
+        assert!(!validate_json(test));
+        let test: Cow<'static, str> = String::from("{").into();
+        assert!(!validate_json(test));
+    }
+
+    #[test]
+    fn test_validate_json_as() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct Settings {
+            theme: String,
+            font_size: u8,
+        }
+
+        assert!(validate_json_as::<Settings, _>(
+            r#"{"theme": "dark", "font_size": 12}"#
+        ));
+        assert!(!validate_json_as::<Settings, _>(r#"{"theme": "dark"}"#));
+        assert!(!validate_json_as::<Settings, _>(
+            r#"{"theme": "dark", "font_size": 1200}"#
+        ));
+        assert!(validate_json_as::<HashMap<String, u32>, _>(r#"{"a": 1}"#));
+        assert!(!validate_json_as::<Vec<u32>, _>(r#"{"a": 1}"#));
+    }
+}
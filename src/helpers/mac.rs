@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+/// Parses the given string into MAC address octets.
+///
+/// Accepts colon (`00:1a:2b:3c:4d:5e`), dash (`00-1A-2B-3C-4D-5E`) and dotted
+/// Cisco (`001a.2b3c.4d5e`) notations for both EUI-48 and EUI-64 addresses.
+#[must_use]
+pub fn parse_mac<'a, T>(val: T) -> Option<Vec<u8>>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    let groups: Vec<&str> = if val.contains(':') {
+        val.split(':').collect()
+    } else if val.contains('-') {
+        val.split('-').collect()
+    } else if val.contains('.') {
+        val.split('.').collect()
+    } else {
+        return None;
+    };
+
+    let group_len = if val.contains('.') { 4 } else { 2 };
+
+    if groups.len() * group_len != 12 && groups.len() * group_len != 16 {
+        return None;
+    }
+
+    let mut octets = vec![];
+
+    for group in groups {
+        if group.len() != group_len || !group.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        for i in (0..group_len).step_by(2) {
+            octets.push(u8::from_str_radix(&group[i..i + 2], 16).ok()?);
+        }
+    }
+
+    Some(octets)
+}
+
+/// Validates whether the given string is an EUI-48 MAC address
+#[must_use]
+pub fn validate_mac_eui48<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_mac(val).is_some_and(|octets| octets.len() == 6)
+}
+
+/// Validates whether the given string is an EUI-64 MAC address
+#[must_use]
+pub fn validate_mac_eui64<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_mac(val).is_some_and(|octets| octets.len() == 8)
+}
+
+/// Validates whether the given string is a MAC address
+#[must_use]
+pub fn validate_mac<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_mac(val).is_some()
+}
+
+/// Formats the given MAC address in the canonical lowercase colon form,
+/// returns `None` if the value is not a valid MAC address
+#[must_use]
+pub fn normalize_mac<'a, T>(val: T) -> Option<String>
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_mac(val).map(|octets| {
+        octets
+            .iter()
+            .map(|o| format!("{:02x}", o))
+            .collect::<Vec<String>>()
+            .join(":")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{normalize_mac, validate_mac, validate_mac_eui48, validate_mac_eui64};
+
+    #[test]
+    fn test_validate_mac() {
+        let tests = vec![
+            ("00:1a:2b:3c:4d:5e", true),
+            ("00-1A-2B-3C-4D-5E", true),
+            ("001a.2b3c.4d5e", true),
+            ("00:1a:2b:3c:4d:5e:6f:70", true),
+            ("00-1a-2b-3c-4d-5e-6f-70", true),
+            ("001a.2b3c.4d5e.6f70", true),
+            ("00:1a:2b:3c:4d", false),
+            ("00:1a:2b:3c:4d:5e:6f", false),
+            ("00:1a:2b-3c:4d:5e", false),
+            ("00:1a:2b:3c:4d:5g", false),
+            ("0:1a:2b:3c:4d:5e0", false),
+            ("001a2b3c4d5e", false),
+            ("001a.2b3c.4d5", false),
+            ("+0:1a:2b:3c:4d:5e", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_mac(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_mac_cow() {
+        let test: Cow<'static, str> = "00:1a:2b:3c:4d:5e".into();
+        assert!(validate_mac(test));
+        let test: Cow<'static, str> = String::from("00:1a:2b:3c:4d:5e").into();
+        assert!(validate_mac(test));
+        let test: Cow<'static, str> = "00:1a:2b:3c:4d".into();
+        assert!(!validate_mac(test));
+        let test: Cow<'static, str> = String::from("00:1a:2b:3c:4d").into();
+        assert!(!validate_mac(test));
+    }
+
+    #[test]
+    fn test_validate_mac_eui48() {
+        let tests = vec![
+            ("00:1a:2b:3c:4d:5e", true),
+            ("001a.2b3c.4d5e", true),
+            ("00:1a:2b:3c:4d:5e:6f:70", false),
+            ("foo", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_mac_eui48(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_mac_eui64() {
+        let tests = vec![
+            ("00:1a:2b:3c:4d:5e:6f:70", true),
+            ("001a.2b3c.4d5e.6f70", true),
+            ("00:1a:2b:3c:4d:5e", false),
+            ("foo", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_mac_eui64(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_normalize_mac() {
+        let tests = vec![
+            ("00-1A-2B-3C-4D-5E", Some("00:1a:2b:3c:4d:5e".to_string())),
+            ("001A.2B3C.4D5E", Some("00:1a:2b:3c:4d:5e".to_string())),
+            (
+                "001a.2b3c.4d5e.6f70",
+                Some("00:1a:2b:3c:4d:5e:6f:70".to_string()),
+            ),
+            ("00:1a:2b", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(normalize_mac(input), expected);
+        }
+    }
+}
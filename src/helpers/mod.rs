@@ -1,6 +1,37 @@
+pub mod base64;
+pub mod bic;
 pub mod card;
+pub mod case;
+pub mod coerce;
+pub mod color;
+#[cfg(feature = "country-codes")]
+pub mod country_code;
+#[cfg(feature = "currency-codes")]
+pub mod currency_code;
 pub mod email;
+pub mod geo;
+pub mod hex;
+pub mod html;
+pub mod iban;
 pub mod ip;
+pub mod json;
+pub mod jwt;
+#[cfg(feature = "language-tags")]
+pub mod language_tag;
+pub mod mac;
 pub mod non_control_character;
+pub mod number;
+pub mod password;
 pub mod phone;
+pub mod port;
+pub mod postal_code;
+pub mod product_codes;
+pub mod semver;
+pub mod slug;
+pub mod socket_address;
+pub mod tax_id;
+pub mod text;
+#[cfg(feature = "timezones")]
+pub mod timezone;
+pub mod unicode;
 pub mod url;
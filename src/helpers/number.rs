@@ -0,0 +1,270 @@
+use std::borrow::Cow;
+
+/// Converts the value through its shortest representation, e.g. `19.99f32` becomes `19.99`
+/// instead of `19.989999771118164`
+#[must_use]
+pub fn f32_to_f64(value: f32) -> f64 {
+    value.to_string().parse().unwrap_or(value as f64)
+}
+
+/// Validates whether the value is a multiple of the step, e.g. `12` of `6` or `0.3` of `0.1`.
+/// Floating point rounding errors are tolerated, zero or non finite step is never valid.
+#[must_use]
+pub fn validate_multiple_of(value: f64, step: f64) -> bool {
+    if !value.is_finite() || !step.is_finite() || step == 0.0 {
+        return false;
+    }
+
+    let quotient = value / step;
+
+    (quotient - quotient.round()).abs() < 1e-9
+}
+
+/// Number of decimal places in the shortest representation of the value,
+/// e.g. `2` for `19.99`. Non finite values have none.
+#[must_use]
+pub fn decimal_places(value: f64) -> usize {
+    if !value.is_finite() {
+        return 0;
+    }
+
+    value
+        .to_string()
+        .split_once('.')
+        .map(|(_, decimals)| decimals.len())
+        .unwrap_or(0)
+}
+
+/// Validates whether the value has at most the given number of decimal places
+#[must_use]
+pub fn validate_decimal_places(value: f64, max: usize) -> bool {
+    value.is_finite() && decimal_places(value) <= max
+}
+
+/// Validates whether the value is a finite whole number, e.g. `3.0`
+#[must_use]
+pub fn validate_integer(value: f64) -> bool {
+    value.is_finite() && value.fract() == 0.0
+}
+
+/// Rounds the value to the given number of decimal places, halfway cases away from zero,
+/// e.g. `19.995` to `2` decimals is `20.0`. Values with fewer decimals are returned as they are.
+#[must_use]
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    if !value.is_finite() || decimal_places(value) <= decimals as usize {
+        return value;
+    }
+
+    let factor = 10f64.powi(decimals as i32);
+
+    (value * factor).round() / factor
+}
+
+/// Validates whether the string is a number, e.g. `12`, `-0.5` or `1e3`.
+/// Surrounding whitespace, `inf` and `NaN` are not allowed.
+#[must_use]
+pub fn validate_numeric<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_number(val).is_some()
+}
+
+/// Validates whether the string is a whole number with an optional sign, e.g. `12` or `-3`
+#[must_use]
+pub fn validate_integer_string<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let digits = val.strip_prefix(['+', '-']).unwrap_or(&val);
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Validates whether the string is a decimal number without an exponent, e.g. `12`
+/// or `-19.99`. Both the integer and the fraction part need at least one digit.
+#[must_use]
+pub fn validate_decimal_string<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    match val.split_once('.') {
+        Some((integer, fraction)) => {
+            validate_integer_string(integer)
+                && !fraction.is_empty()
+                && fraction.chars().all(|c| c.is_ascii_digit())
+        }
+        None => validate_integer_string(val),
+    }
+}
+
+/// Parses the string as a finite number, see [`validate_numeric`]
+#[must_use]
+pub fn parse_number<'a, T>(val: T) -> Option<f64>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    // Rust parses `inf`, `infinity` and `NaN` as well
+    if !val
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+    {
+        return None;
+    }
+
+    val.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        decimal_places, f32_to_f64, parse_number, round_to, validate_decimal_places,
+        validate_decimal_string, validate_integer, validate_integer_string, validate_multiple_of,
+        validate_numeric,
+    };
+
+    #[test]
+    fn test_validate_multiple_of() {
+        let tests = vec![
+            (12.0, 6.0, true),
+            (0.0, 6.0, true),
+            (-18.0, 6.0, true),
+            (13.0, 6.0, false),
+            (0.3, 0.1, true),
+            (1.05, 0.05, true),
+            (1.07, 0.05, false),
+            (12.0, 0.0, false),
+            (f64::NAN, 6.0, false),
+            (f64::INFINITY, 6.0, false),
+        ];
+
+        for (value, step, expected) in tests {
+            assert_eq!(
+                validate_multiple_of(value, step),
+                expected,
+                "{} {}",
+                value,
+                step
+            );
+        }
+    }
+
+    #[test]
+    fn test_decimal_places() {
+        let tests = vec![
+            (19.99, 2),
+            (19.9, 1),
+            (20.0, 0),
+            (-0.125, 3),
+            (0.1 + 0.2, 17),
+            (0.0000001, 7),
+            (f64::NAN, 0),
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(decimal_places(value), expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_f32_to_f64() {
+        assert_eq!(f32_to_f64(19.99), 19.99);
+        assert_eq!(f32_to_f64(0.1), 0.1);
+        assert_eq!(f32_to_f64(-3.0), -3.0);
+        assert_eq!(decimal_places(f32_to_f64(19.99)), 2);
+        assert!(f32_to_f64(f32::NAN).is_nan());
+        assert_eq!(f32_to_f64(f32::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_validate_decimal_places() {
+        assert!(validate_decimal_places(19.99, 2));
+        assert!(validate_decimal_places(19.0, 0));
+        assert!(!validate_decimal_places(19.999, 2));
+        assert!(!validate_decimal_places(f64::NAN, 2));
+    }
+
+    #[test]
+    fn test_validate_integer() {
+        assert!(validate_integer(3.0));
+        assert!(validate_integer(-3.0));
+        assert!(!validate_integer(3.5));
+        assert!(!validate_integer(f64::NAN));
+        assert!(!validate_integer(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_validate_numeric_strings() {
+        let tests = vec![
+            // (input, numeric, integer, decimal)
+            ("12", true, true, true),
+            ("-3", true, true, true),
+            ("+7", true, true, true),
+            ("19.99", true, false, true),
+            ("-0.5", true, false, true),
+            (".5", true, false, false),
+            ("5.", true, false, false),
+            ("1e3", true, false, false),
+            ("", false, false, false),
+            ("-", false, false, false),
+            (" 12", false, false, false),
+            ("12a", false, false, false),
+            ("1.2.3", false, false, false),
+            ("inf", false, false, false),
+            ("NaN", false, false, false),
+        ];
+
+        for (input, numeric, integer, decimal) in tests {
+            assert_eq!(validate_numeric(input), numeric, "{}", input);
+            assert_eq!(validate_integer_string(input), integer, "{}", input);
+            assert_eq!(validate_decimal_string(input), decimal, "{}", input);
+        }
+
+        assert_eq!(parse_number("-19.99"), Some(-19.99));
+        assert_eq!(parse_number("1e400"), None);
+    }
+
+    #[test]
+    fn test_validate_numeric_cow() {
+        let test: Cow<'static, str> = "12".into();
+        assert!(validate_numeric(test));
+        let test: Cow<'static, str> = String::from("12").into();
+        assert!(validate_numeric(test));
+        let test: Cow<'static, str> = "twelve".into();
+        assert!(!validate_numeric(test));
+        let test: Cow<'static, str> = String::from("twelve").into();
+        assert!(!validate_numeric(test));
+    }
+
+    #[test]
+    fn test_round_to() {
+        let tests = vec![
+            (19.994, 2, 19.99),
+            (19.996, 2, 20.0),
+            (-1.25, 1, -1.3),
+            (12.5, 0, 13.0),
+            (0.1 + 0.2, 2, 0.3),
+            (19.9, 2, 19.9),
+            (1e300, 2, 1e300),
+        ];
+
+        for (value, decimals, expected) in tests {
+            assert_eq!(
+                round_to(value, decimals),
+                expected,
+                "{} {}",
+                value,
+                decimals
+            );
+        }
+
+        assert!(round_to(f64::NAN, 2).is_nan());
+    }
+}
@@ -0,0 +1,437 @@
+use std::borrow::Cow;
+#[cfg(feature = "common-passwords")]
+use std::collections::HashSet;
+
+#[cfg(feature = "common-passwords")]
+use lazy_static::lazy_static;
+
+#[cfg(feature = "common-passwords")]
+lazy_static! {
+    static ref COMMON_PASSWORDS: HashSet<&'static str> =
+        include_str!("data/common_passwords.txt").lines().collect();
+}
+
+/// Requirements a password has to meet, every requirement is disabled by default
+#[derive(Clone, Debug, Default)]
+pub struct PasswordPolicy {
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    lowercase: bool,
+    uppercase: bool,
+    digit: bool,
+    symbol: bool,
+    max_repeated: Option<usize>,
+    max_sequential: Option<usize>,
+    min_score: Option<u8>,
+    #[cfg(feature = "common-passwords")]
+    common: bool,
+}
+
+impl PasswordPolicy {
+    /// Create policy without any requirements
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum number of characters
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.min_length = Some(min);
+
+        self
+    }
+
+    /// Maximum number of characters
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+
+        self
+    }
+
+    /// Require at least one lowercase letter
+    pub fn lowercase(mut self) -> Self {
+        self.lowercase = true;
+
+        self
+    }
+
+    /// Require at least one uppercase letter
+    pub fn uppercase(mut self) -> Self {
+        self.uppercase = true;
+
+        self
+    }
+
+    /// Require at least one digit
+    pub fn digit(mut self) -> Self {
+        self.digit = true;
+
+        self
+    }
+
+    /// Require at least one character that is neither a letter nor a digit
+    pub fn symbol(mut self) -> Self {
+        self.symbol = true;
+
+        self
+    }
+
+    /// Maximum number of the same character in a row, e.g. `2` rejects `aaa`
+    pub fn max_repeated(mut self, max: usize) -> Self {
+        self.max_repeated = Some(max);
+
+        self
+    }
+
+    /// Maximum length of an ascending or descending character run, e.g. `3` rejects `abcd` and `4321`
+    pub fn max_sequential(mut self, max: usize) -> Self {
+        self.max_sequential = Some(max);
+
+        self
+    }
+
+    /// Minimum strength score from `0` (too guessable) to `4` (very unguessable),
+    /// see [`password_score`]
+    pub fn min_score(mut self, score: u8) -> Self {
+        self.min_score = Some(score);
+
+        self
+    }
+
+    /// Reject passwords from the bundled list of common passwords
+    #[cfg(feature = "common-passwords")]
+    pub fn not_common(mut self) -> Self {
+        self.common = true;
+
+        self
+    }
+}
+
+/// Requirement of the password policy that the password did not meet
+#[derive(Clone, Debug, PartialEq)]
+pub enum PasswordError {
+    MinLength(usize),
+    MaxLength(usize),
+    Lowercase,
+    Uppercase,
+    Digit,
+    Symbol,
+    Repeated(usize),
+    Sequential(usize),
+    Score(u8),
+    Common,
+}
+
+impl PasswordError {
+    /// Error code that is reported for the field
+    pub fn code(&self) -> String {
+        match self {
+            PasswordError::MinLength(min) => format!("password_min_length:{}", min),
+            PasswordError::MaxLength(max) => format!("password_max_length:{}", max),
+            PasswordError::Lowercase => "password_lowercase".to_string(),
+            PasswordError::Uppercase => "password_uppercase".to_string(),
+            PasswordError::Digit => "password_digit".to_string(),
+            PasswordError::Symbol => "password_symbol".to_string(),
+            PasswordError::Repeated(max) => format!("password_repeated:{}", max),
+            PasswordError::Sequential(max) => format!("password_sequential:{}", max),
+            PasswordError::Score(min) => format!("password_score:{}", min),
+            PasswordError::Common => "password_common".to_string(),
+        }
+    }
+}
+
+/// Length of the longest run of the same character
+fn longest_repeated(chars: &[char]) -> usize {
+    longest_run(chars, |a, b| a == b)
+}
+
+/// Length of the longest run of consecutive characters, e.g. `abc` or `321`
+fn longest_sequential(chars: &[char]) -> usize {
+    let ascending = longest_run(chars, |a, b| a as u32 + 1 == b as u32);
+    let descending = longest_run(chars, |a, b| a as u32 == b as u32 + 1);
+
+    ascending.max(descending)
+}
+
+fn longest_run<F>(chars: &[char], follows: F) -> usize
+where
+    F: Fn(char, char) -> bool,
+{
+    let mut longest = chars.len().min(1);
+    let mut current = longest;
+
+    for pair in chars.windows(2) {
+        if follows(pair[0], pair[1]) {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 1;
+        }
+    }
+
+    longest
+}
+
+/// Checks whether the password is on the bundled list of about 300 of the most common
+/// passwords, compared case insensitively
+#[cfg(feature = "common-passwords")]
+#[must_use]
+pub fn is_common_password<'a, T>(password: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    COMMON_PASSWORDS.contains(password.into().to_lowercase().as_str())
+}
+
+/// Estimates the password strength on a `0` to `4` scale, using the same guess
+/// thresholds as zxcvbn (`10^3`, `10^6`, `10^8` and `10^10` guesses).
+///
+/// Every character is counted as 10 guesses and every additional character class
+/// multiplies them by 10, characters that repeat or continue a sequence of the previous
+/// one don't add to it. Dictionary words are not detected, apart from the bundled
+/// common passwords when the `common-passwords` feature is enabled.
+#[must_use]
+pub fn password_score<'a, T>(password: T) -> u8
+where
+    T: Into<Cow<'a, str>>,
+{
+    let password = password.into();
+
+    #[cfg(feature = "common-passwords")]
+    if is_common_password(password.as_ref()) {
+        return 0;
+    }
+
+    let chars: Vec<char> = password.chars().collect();
+
+    let classes = [
+        chars.iter().any(|c| c.is_lowercase()),
+        chars.iter().any(|c| c.is_uppercase()),
+        chars.iter().any(|c| c.is_ascii_digit()),
+        chars.iter().any(|c| !c.is_alphanumeric()),
+    ]
+    .iter()
+    .filter(|used| **used)
+    .count();
+
+    let effective_length = chars.len().min(1)
+        + chars
+            .windows(2)
+            .filter(|pair| (pair[0] as i64 - pair[1] as i64).abs() > 1)
+            .count();
+
+    // Order of magnitude of the number of guesses
+    let guesses = effective_length + classes.saturating_sub(1);
+
+    match guesses {
+        0..=2 => 0,
+        3..=5 => 1,
+        6..=7 => 2,
+        8..=9 => 3,
+        _ => 4,
+    }
+}
+
+/// Validates the password against the policy, returning every requirement that was not met
+pub fn validate_password<'a, T>(
+    password: T,
+    policy: &PasswordPolicy,
+) -> Result<(), Vec<PasswordError>>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let password = password.into();
+    let chars: Vec<char> = password.chars().collect();
+    let mut errors = vec![];
+
+    if let Some(min) = policy.min_length {
+        if chars.len() < min {
+            errors.push(PasswordError::MinLength(min));
+        }
+    }
+
+    if let Some(max) = policy.max_length {
+        if chars.len() > max {
+            errors.push(PasswordError::MaxLength(max));
+        }
+    }
+
+    if policy.lowercase && !chars.iter().any(|c| c.is_lowercase()) {
+        errors.push(PasswordError::Lowercase);
+    }
+
+    if policy.uppercase && !chars.iter().any(|c| c.is_uppercase()) {
+        errors.push(PasswordError::Uppercase);
+    }
+
+    if policy.digit && !chars.iter().any(|c| c.is_ascii_digit()) {
+        errors.push(PasswordError::Digit);
+    }
+
+    if policy.symbol && !chars.iter().any(|c| !c.is_alphanumeric()) {
+        errors.push(PasswordError::Symbol);
+    }
+
+    if let Some(max) = policy.max_repeated {
+        if longest_repeated(&chars) > max {
+            errors.push(PasswordError::Repeated(max));
+        }
+    }
+
+    if let Some(max) = policy.max_sequential {
+        if longest_sequential(&chars) > max {
+            errors.push(PasswordError::Sequential(max));
+        }
+    }
+
+    #[cfg(feature = "common-passwords")]
+    if policy.common && is_common_password(password.as_ref()) {
+        errors.push(PasswordError::Common);
+    }
+
+    if let Some(min) = policy.min_score {
+        if password_score(password.as_ref()) < min {
+            errors.push(PasswordError::Score(min));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks whether the password is the same as the other value, e.g. the username,
+/// ignoring the case. For emails the local part is compared as well.
+#[must_use]
+pub fn password_matches<'a, T, V>(password: T, other: V) -> bool
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    let password = password.into().to_lowercase();
+    let other = other.into().to_lowercase();
+
+    if other.is_empty() {
+        return false;
+    }
+
+    match other.split_once('@') {
+        Some((local, _)) if !local.is_empty() => password == other || password == local,
+        _ => password == other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        password_matches, password_score, validate_password, PasswordError, PasswordPolicy,
+    };
+
+    #[test]
+    fn test_validate_password() {
+        let policy = PasswordPolicy::new()
+            .min_length(8)
+            .max_length(20)
+            .lowercase()
+            .uppercase()
+            .digit()
+            .symbol()
+            .max_repeated(2)
+            .max_sequential(3);
+
+        let tests = vec![
+            ("Tr0ub4dor&3", Ok(())),
+            ("Šifra-2024x", Ok(())),
+            ("Ab1!", Err(vec![PasswordError::MinLength(8)])),
+            (
+                "Ab1!Ab1!Ab1!Ab1!Ab1!Ab1!",
+                Err(vec![PasswordError::MaxLength(20)]),
+            ),
+            (
+                "password",
+                Err(vec![
+                    PasswordError::Uppercase,
+                    PasswordError::Digit,
+                    PasswordError::Symbol,
+                ]),
+            ),
+            ("Paaass-w0rd", Err(vec![PasswordError::Repeated(2)])),
+            ("Pass-w0rd-1234", Err(vec![PasswordError::Sequential(3)])),
+            ("Pass-w0rd-dcba", Err(vec![PasswordError::Sequential(3)])),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_password(input, &policy), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_password_cow() {
+        let policy = PasswordPolicy::new().min_length(8);
+
+        let test: Cow<'static, str> = "Tr0ub4dor&3".into();
+        assert!(validate_password(test, &policy).is_ok());
+        let test: Cow<'static, str> = String::from("Tr0ub4dor&3").into();
+        assert!(validate_password(test, &policy).is_ok());
+        let test: Cow<'static, str> = "short".into();
+        assert!(validate_password(test, &policy).is_err());
+        let test: Cow<'static, str> = String::from("short").into();
+        assert!(validate_password(test, &policy).is_err());
+    }
+
+    #[test]
+    fn test_password_score() {
+        let tests = vec![
+            ("", 0),
+            ("aaaaaaaaaaaa", 0),
+            ("abcdefgh", 0),
+            ("cat", 1),
+            ("kitten", 1),
+            ("kitten4", 2),
+            ("Kitten42", 3),
+            ("correct horse battery staple", 4),
+            ("Tr0ub4dor&3", 4),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(password_score(input), expected, "{}", input);
+        }
+    }
+
+    #[cfg(feature = "common-passwords")]
+    #[test]
+    fn test_common_password() {
+        use super::is_common_password;
+
+        assert!(is_common_password("password"));
+        assert!(is_common_password("QWERTY"));
+        assert!(!is_common_password("Tr0ub4dor&3"));
+
+        let policy = PasswordPolicy::new().not_common();
+
+        assert_eq!(
+            validate_password("letmein", &policy),
+            Err(vec![PasswordError::Common])
+        );
+        assert_eq!(password_score("Password1"), 0);
+    }
+
+    #[test]
+    fn test_password_matches() {
+        let tests = vec![
+            ("john.doe", "john.doe", true),
+            ("John.Doe", "john.doe", true),
+            ("john.doe", "john.doe@example.com", true),
+            ("john.doe@example.com", "john.doe@example.com", true),
+            ("john.doe1", "john.doe", false),
+            ("", "", false),
+        ];
+
+        for (password, other, expected) in tests {
+            assert_eq!(password_matches(password, other), expected);
+        }
+    }
+}
@@ -1,22 +1,193 @@
 use std::borrow::Cow;
 
+use phonenumber::{country, PhoneNumber};
+
+pub use phonenumber::Mode as PhoneFormat;
+pub use phonenumber::Type as PhoneType;
+
+/// Options for the region aware phone validation
+#[derive(Clone, Debug, Default)]
+pub struct PhoneOptions {
+    region: Option<country::Id>,
+    countries: Vec<country::Id>,
+    types: Vec<PhoneType>,
+}
+
+impl PhoneOptions {
+    /// Create options without any restrictions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Region used to parse numbers given without the `+` prefix, e.g. `"HR"`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region is not a known country code
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = Some(parse_region(region));
+
+        self
+    }
+
+    /// Only accept numbers belonging to one of the given countries, e.g. `["HR", "SI"]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the countries is not a known country code
+    pub fn countries<T: AsRef<str>>(mut self, countries: Vec<T>) -> Self {
+        self.countries = countries.iter().map(|c| parse_region(c.as_ref())).collect();
+
+        self
+    }
+
+    /// Only accept numbers of the given types
+    pub fn types(mut self, types: Vec<PhoneType>) -> Self {
+        self.types = types;
+
+        self
+    }
+}
+
+/// Reason why the phone number did not pass the region aware validation
+#[derive(Clone, Debug, PartialEq)]
+pub enum PhoneError {
+    /// Number could not be parsed or is not valid
+    Invalid,
+    /// Number belongs to a country that is not allowed, holds the parsed country
+    Country(String),
+    /// Number is of a type that is not allowed, holds the parsed type
+    Type(String),
+}
+
+impl PhoneError {
+    /// Error code that is reported for the field
+    pub fn code(&self) -> String {
+        match self {
+            PhoneError::Invalid => "phone".to_string(),
+            PhoneError::Country(country) => format!("phone_country:{}", country),
+            PhoneError::Type(kind) => format!("phone_type:{}", kind),
+        }
+    }
+}
+
+fn find_region(region: &str) -> Option<country::Id> {
+    region.to_uppercase().parse().ok()
+}
+
+fn parse_region(region: &str) -> country::Id {
+    find_region(region).unwrap_or_else(|| panic!("Unknown phone region `{}`", region))
+}
+
+/// Parses the phone number, using the region for numbers without the `+` prefix.
+/// Returns `None` if the region is not a known country code.
 #[must_use]
-pub fn validate_phone<'a, T>(phone_number: T) -> bool
+pub fn parse_phone<'a, T>(phone_number: T, region: Option<&str>) -> Option<PhoneNumber>
 where
     T: Into<Cow<'a, str>>,
 {
-    if let Ok(parsed) = phonenumber::parse(None, phone_number.into()) {
-        phonenumber::is_valid(&parsed)
+    let region = match region {
+        Some(region) => Some(find_region(region)?),
+        None => None,
+    };
+    let parsed = phonenumber::parse(region, phone_number.into()).ok()?;
+
+    if phonenumber::is_valid(&parsed) {
+        Some(parsed)
     } else {
-        false
+        None
+    }
+}
+
+/// Formats the phone number in the given format, returns `None` if it can't be parsed
+#[must_use]
+pub fn format_phone<'a, T>(
+    phone_number: T,
+    region: Option<&str>,
+    format: PhoneFormat,
+) -> Option<String>
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_phone(phone_number, region).map(|parsed| parsed.format().mode(format).to_string())
+}
+
+/// Formats the phone number in the given format, using the region of the options for
+/// numbers without the `+` prefix. Country and type restrictions are not checked,
+/// returns `None` if it can't be parsed.
+#[must_use]
+pub fn format_phone_with<'a, T>(
+    phone_number: T,
+    options: &PhoneOptions,
+    format: PhoneFormat,
+) -> Option<String>
+where
+    T: Into<Cow<'a, str>>,
+{
+    match phonenumber::parse(options.region, phone_number.into()) {
+        Ok(parsed) if phonenumber::is_valid(&parsed) => {
+            Some(parsed.format().mode(format).to_string())
+        }
+        _ => None,
+    }
+}
+
+#[must_use]
+pub fn validate_phone<'a, T>(phone_number: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    parse_phone(phone_number, None).is_some()
+}
+
+/// Validates the phone number against the given region, country and type restrictions
+pub fn validate_phone_with<'a, T>(phone_number: T, options: &PhoneOptions) -> Result<(), PhoneError>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let parsed = match phonenumber::parse(options.region, phone_number.into()) {
+        Ok(parsed) if phonenumber::is_valid(&parsed) => parsed,
+        _ => return Err(PhoneError::Invalid),
+    };
+
+    if !options.countries.is_empty() {
+        match parsed.country().id() {
+            Some(id) if options.countries.contains(&id) => (),
+            Some(id) => return Err(PhoneError::Country(id.as_ref().to_string())),
+            None => return Err(PhoneError::Country(parsed.country().code().to_string())),
+        }
+    }
+
+    if !options.types.is_empty() {
+        let kind = parsed.number_type(&phonenumber::metadata::DATABASE);
+
+        let allowed = options.types.iter().any(|t| {
+            *t == kind
+                || (kind == PhoneType::FixedLineOrMobile
+                    && (*t == PhoneType::FixedLine || *t == PhoneType::Mobile))
+        });
+
+        if !allowed {
+            let kind = serde_json::to_value(kind)
+                .ok()
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .unwrap_or_default();
+
+            return Err(PhoneError::Type(kind));
+        }
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_phone;
+    use super::{
+        format_phone, format_phone_with, validate_phone, validate_phone_with, PhoneError,
+        PhoneFormat, PhoneOptions, PhoneType,
+    };
 
     #[test]
     fn test_phone() {
@@ -40,12 +211,121 @@ mod tests {
     #[test]
     fn test_phone_cow() {
         let test: Cow<'static, str> = "+1 (415) 237-0800".into();
-        assert_eq!(validate_phone(test), true);
+        assert!(validate_phone(test));
         let test: Cow<'static, str> = String::from("+1 (415) 237-0800").into();
-        assert_eq!(validate_phone(test), true);
+        assert!(validate_phone(test));
         let test: Cow<'static, str> = "TEXT".into();
-        assert_eq!(validate_phone(test), false);
+        assert!(!validate_phone(test));
         let test: Cow<'static, str> = String::from("TEXT").into();
-        assert_eq!(validate_phone(test), false);
+        assert!(!validate_phone(test));
+    }
+
+    #[test]
+    fn test_phone_with_region() {
+        let options = PhoneOptions::new().region("HR");
+
+        let tests = vec![
+            ("091 234 5678", Ok(())),
+            ("+385 91 234 5678", Ok(())),
+            ("00385912345678", Ok(())),
+            ("+33642926829", Ok(())),
+            ("0642926829", Err(PhoneError::Invalid)),
+            ("TEXT", Err(PhoneError::Invalid)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_phone_with(input, &options), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_phone_with_countries() {
+        let options = PhoneOptions::new().countries(vec!["HR", "si"]);
+
+        let tests = vec![
+            ("+385 91 234 5678", Ok(())),
+            ("+386 31 234 567", Ok(())),
+            ("+33642926829", Err(PhoneError::Country("FR".to_string()))),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_phone_with(input, &options), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_phone_with_types() {
+        let options = PhoneOptions::new()
+            .region("HR")
+            .types(vec![PhoneType::Mobile]);
+
+        let tests = vec![
+            ("091 234 5678", Ok(())),
+            ("+1 (415) 237-0800", Ok(())),
+            (
+                "01 4567 890",
+                Err(PhoneError::Type("fixed_line".to_string())),
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_phone_with(input, &options), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_format_phone() {
+        let tests = vec![
+            ("091 234 5678", Some("HR"), Some("+385912345678")),
+            ("+385 (91) 234-5678", Some("HR"), Some("+385912345678")),
+            ("00385912345678", Some("HR"), Some("+385912345678")),
+            ("+385 (91) 234-5678", None, Some("+385912345678")),
+            ("091 234 5678", None, None),
+            ("TEXT", Some("HR"), None),
+            ("091 234 5678", Some("XX"), None),
+        ];
+
+        for (input, region, expected) in tests {
+            assert_eq!(
+                format_phone(input, region, PhoneFormat::E164),
+                expected.map(|e| e.to_string()),
+                "{}",
+                input
+            );
+        }
+
+        assert_eq!(
+            format_phone("00385912345678", Some("HR"), PhoneFormat::National),
+            Some("091 234 5678".to_string())
+        );
+        assert_eq!(
+            format_phone("00385912345678", Some("HR"), PhoneFormat::International),
+            Some("+385 91 234 5678".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_phone_with() {
+        let options = PhoneOptions::new().region("hr");
+
+        assert_eq!(
+            format_phone_with("091 234 5678", &options, PhoneFormat::E164),
+            Some("+385912345678".to_string())
+        );
+        assert_eq!(
+            format_phone_with("+33642926829", &options, PhoneFormat::National),
+            Some("06 42 92 68 29".to_string())
+        );
+        assert_eq!(
+            format_phone_with("091 234 5678", &PhoneOptions::new(), PhoneFormat::E164),
+            None
+        );
+        assert_eq!(format_phone_with("TEXT", &options, PhoneFormat::E164), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown phone region `XX`")]
+    fn test_phone_options_unknown_region() {
+        let _ = PhoneOptions::new().region("XX");
     }
 }
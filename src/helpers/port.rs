@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+/// Validates whether the given string is a TCP/UDP port in the `1-65535` range
+#[must_use]
+pub fn validate_port<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    val.chars().all(|c| c.is_ascii_digit()) && val.parse::<u16>().is_ok_and(|p| p != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_port;
+
+    #[test]
+    fn test_validate_port() {
+        let tests = vec![
+            ("1", true),
+            ("80", true),
+            ("8080", true),
+            ("65535", true),
+            ("0", false),
+            ("65536", false),
+            ("-1", false),
+            ("+80", false),
+            ("80 ", false),
+            ("http", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_port(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_validate_port_cow() {
+        let test: Cow<'static, str> = "443".into();
+        assert!(validate_port(test));
+        let test: Cow<'static, str> = String::from("443").into();
+        assert!(validate_port(test));
+        let test: Cow<'static, str> = "70000".into();
+        assert!(!validate_port(test));
+        let test: Cow<'static, str> = String::from("70000").into();
+        assert!(!validate_port(test));
+    }
+}
@@ -0,0 +1,199 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Postal code patterns per country, matched against the uppercase code without spaces
+/// and dashes. Capture groups are joined with the separator to get the canonical form.
+const POSTAL_CODE_FORMATS: &[(&str, &str, &str)] = &[
+    ("AT", r"^([0-9]{4})$", ""),
+    ("AU", r"^([0-9]{4})$", ""),
+    ("BA", r"^([0-9]{5})$", ""),
+    ("BE", r"^([0-9]{4})$", ""),
+    ("BG", r"^([0-9]{4})$", ""),
+    ("BR", r"^([0-9]{5})([0-9]{3})$", "-"),
+    (
+        "CA",
+        r"^([ABCEGHJ-NPRSTVXY][0-9][ABCEGHJ-NPRSTV-Z])([0-9][ABCEGHJ-NPRSTV-Z][0-9])$",
+        " ",
+    ),
+    ("CH", r"^([0-9]{4})$", ""),
+    ("CN", r"^([0-9]{6})$", ""),
+    ("CZ", r"^([0-9]{3})([0-9]{2})$", " "),
+    ("DE", r"^([0-9]{5})$", ""),
+    ("DK", r"^([0-9]{4})$", ""),
+    ("EE", r"^([0-9]{5})$", ""),
+    ("ES", r"^((?:0[1-9]|[1-4][0-9]|5[0-2])[0-9]{3})$", ""),
+    ("FI", r"^([0-9]{5})$", ""),
+    ("FR", r"^([0-9]{5})$", ""),
+    (
+        "GB",
+        r"^(GIR|[A-Z]{1,2}[0-9][A-Z0-9]?)([0-9][A-Z]{2})$",
+        " ",
+    ),
+    ("GR", r"^([0-9]{3})([0-9]{2})$", " "),
+    ("HR", r"^([0-9]{5})$", ""),
+    ("HU", r"^([0-9]{4})$", ""),
+    (
+        "IE",
+        r"^([AC-FHKNPRTV-Y][0-9]{2}|D6W)([0-9AC-FHKNPRTV-Y]{4})$",
+        " ",
+    ),
+    ("IN", r"^([1-9][0-9]{5})$", ""),
+    ("IT", r"^([0-9]{5})$", ""),
+    ("JP", r"^([0-9]{3})([0-9]{4})$", "-"),
+    ("LT", r"^([0-9]{5})$", ""),
+    ("LU", r"^([0-9]{4})$", ""),
+    ("MX", r"^([0-9]{5})$", ""),
+    ("NL", r"^([1-9][0-9]{3})([A-Z]{2})$", " "),
+    ("NO", r"^([0-9]{4})$", ""),
+    ("NZ", r"^([0-9]{4})$", ""),
+    ("PL", r"^([0-9]{2})([0-9]{3})$", "-"),
+    ("PT", r"^([0-9]{4})([0-9]{3})$", "-"),
+    ("RO", r"^([0-9]{6})$", ""),
+    ("RS", r"^([0-9]{5})$", ""),
+    ("SE", r"^([0-9]{3})([0-9]{2})$", " "),
+    ("SI", r"^([0-9]{4})$", ""),
+    ("SK", r"^([0-9]{3})([0-9]{2})$", " "),
+    ("US", r"^([0-9]{5})([0-9]{4})?$", "-"),
+];
+
+lazy_static! {
+    static ref POSTAL_CODE_RE: HashMap<&'static str, (Regex, &'static str)> = POSTAL_CODE_FORMATS
+        .iter()
+        .map(|(country, pattern, separator)| (*country, (Regex::new(pattern).unwrap(), *separator)))
+        .collect();
+}
+
+fn compact(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// Validates whether the given value is a postal code of the given country.
+///
+/// Casing, spaces and dashes are ignored, so both `SW1A 1AA` and `sw1a1aa` are valid
+/// UK postal codes. Unknown countries are never valid.
+#[must_use]
+pub fn validate_postal_code<'a, T, V>(country: T, value: V) -> bool
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    normalize_postal_code(country, value).is_some()
+}
+
+/// Formats the postal code in the canonical form of the given country, e.g. `sw1a1aa`
+/// becomes `SW1A 1AA` for `GB`. Returns `None` if the value is not a valid postal code.
+#[must_use]
+pub fn normalize_postal_code<'a, T, V>(country: T, value: V) -> Option<String>
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    let country = country.into().trim().to_uppercase();
+    let (re, separator) = POSTAL_CODE_RE.get(country.as_str())?;
+
+    let value = compact(&value.into());
+    let captures = re.captures(&value)?;
+
+    Some(
+        captures
+            .iter()
+            .skip(1)
+            .flatten()
+            .map(|m| m.as_str())
+            .collect::<Vec<&str>>()
+            .join(separator),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{normalize_postal_code, validate_postal_code, POSTAL_CODE_FORMATS};
+
+    #[test]
+    fn test_formats_are_sorted() {
+        assert!(POSTAL_CODE_FORMATS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_validate_postal_code() {
+        let tests = vec![
+            ("HR", "10000", true),
+            ("HR", "1000", false),
+            ("GB", "SW1A 1AA", true),
+            ("GB", "sw1a1aa", true),
+            ("GB", "EC1A 1BB", true),
+            ("GB", "M1 1AE", true),
+            ("GB", "GIR 0AA", true),
+            ("GB", "SW1A 1A", false),
+            ("US", "90210", true),
+            ("US", "90210-1234", true),
+            ("US", "9021", false),
+            ("CA", "K1A 0B1", true),
+            ("CA", "D1A 0B1", false),
+            ("NL", "1234 AB", true),
+            ("NL", "0234 AB", false),
+            ("PL", "00-950", true),
+            ("PT", "1000-001", true),
+            ("JP", "100-0001", true),
+            ("IE", "D02 X285", true),
+            ("ES", "28013", true),
+            ("ES", "53013", false),
+            ("de", "10115", true),
+            ("DE", "1O115", false),
+            ("XX", "12345", false),
+        ];
+
+        for (country, input, expected) in tests {
+            assert_eq!(
+                validate_postal_code(country, input),
+                expected,
+                "{} {}",
+                country,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_postal_code_cow() {
+        let test: Cow<'static, str> = "SW1A 1AA".into();
+        assert!(validate_postal_code("GB", test));
+        let test: Cow<'static, str> = String::from("SW1A 1AA").into();
+        assert!(validate_postal_code("GB", test));
+        let test: Cow<'static, str> = "SW1A".into();
+        assert!(!validate_postal_code("GB", test));
+        let test: Cow<'static, str> = String::from("SW1A").into();
+        assert!(!validate_postal_code("GB", test));
+    }
+
+    #[test]
+    fn test_normalize_postal_code() {
+        let tests = vec![
+            ("GB", "sw1a1aa", Some("SW1A 1AA")),
+            ("GB", " m1  1ae ", Some("M1 1AE")),
+            ("CA", "k1a0b1", Some("K1A 0B1")),
+            ("NL", "1234ab", Some("1234 AB")),
+            ("PL", "00950", Some("00-950")),
+            ("US", "902101234", Some("90210-1234")),
+            ("US", "90210", Some("90210")),
+            ("SE", "11455", Some("114 55")),
+            ("HR", "10 000", Some("10000")),
+            ("GB", "SW1A", None),
+        ];
+
+        for (country, input, expected) in tests {
+            assert_eq!(
+                normalize_postal_code(country, input),
+                expected.map(|e| e.to_string())
+            );
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::borrow::Cow;
+
+/// Removes hyphens and spaces, e.g. `978-0-306-40615-7` becomes `9780306406157`
+fn compact(val: &str) -> String {
+    val.chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect()
+}
+
+fn is_digits(val: &str, len: usize) -> bool {
+    val.len() == len && val.chars().all(|c| c.is_ascii_digit())
+}
+
+/// GS1 check digit of the given digits, without the check digit itself
+fn gtin_check_digit(body: &str) -> u32 {
+    let sum: u32 = body
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 0 { d * 3 } else { d })
+        .sum();
+
+    (10 - sum % 10) % 10
+}
+
+/// Validates the GS1 check digit of the code with the given number of digits
+fn validate_gtin_len(val: &str, len: usize) -> bool {
+    is_digits(val, len) && val[len - 1..].parse::<u32>() == Ok(gtin_check_digit(&val[..len - 1]))
+}
+
+/// Validates whether the given string is an ISBN-10, hyphens and spaces are ignored
+#[must_use]
+pub fn validate_isbn10<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = compact(&val.into());
+
+    if val.len() != 10 || !val.is_ascii() || !is_digits(&val[..9], 9) {
+        return false;
+    }
+
+    let check = match &val[9..] {
+        "X" | "x" => 10,
+        d => match d.parse::<u32>() {
+            Ok(d) => d,
+            Err(_) => return false,
+        },
+    };
+
+    let sum: u32 = val[..9]
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip((2..=10).rev())
+        .map(|(d, w)| d * w)
+        .sum();
+
+    (sum + check) % 11 == 0
+}
+
+/// Validates whether the given string is an ISBN-13, hyphens and spaces are ignored
+#[must_use]
+pub fn validate_isbn13<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = compact(&val.into());
+
+    (val.starts_with("978") || val.starts_with("979")) && validate_gtin_len(&val, 13)
+}
+
+/// Validates whether the given string is an ISBN-10 or ISBN-13
+#[must_use]
+pub fn validate_isbn<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    validate_isbn10(val.as_ref()) || validate_isbn13(val.as_ref())
+}
+
+/// Validates whether the given string is an ISSN, e.g. `0378-5955`
+#[must_use]
+pub fn validate_issn<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let val = match val.split_once('-') {
+        Some((a, b)) if a.len() == 4 => format!("{}{}", a, b),
+        Some(_) => return false,
+        None => val.to_string(),
+    };
+
+    if val.len() != 8 || !val.is_ascii() || !is_digits(&val[..7], 7) {
+        return false;
+    }
+
+    let sum: u32 = val[..7]
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .zip((2..=8).rev())
+        .map(|(d, w)| d * w)
+        .sum();
+
+    match (11 - sum % 11) % 11 {
+        10 => &val[7..] == "X" || &val[7..] == "x",
+        check => val[7..].parse::<u32>() == Ok(check),
+    }
+}
+
+/// Validates whether the given string is an EAN-8 barcode
+#[must_use]
+pub fn validate_ean8<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    validate_gtin_len(&val.into(), 8)
+}
+
+/// Validates whether the given string is an EAN-13 barcode
+#[must_use]
+pub fn validate_ean13<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    validate_gtin_len(&val.into(), 13)
+}
+
+/// Validates whether the given string is a UPC-A barcode
+#[must_use]
+pub fn validate_upc_a<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    validate_gtin_len(&val.into(), 12)
+}
+
+/// Validates whether the given string is a GTIN-14
+#[must_use]
+pub fn validate_gtin14<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    validate_gtin_len(&val.into(), 14)
+}
+
+/// Validates whether the given string is any of GTIN-8 (EAN-8), GTIN-12 (UPC-A),
+/// GTIN-13 (EAN-13) or GTIN-14
+#[must_use]
+pub fn validate_gtin<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    [8, 12, 13, 14]
+        .iter()
+        .any(|len| validate_gtin_len(&val, *len))
+}
+
+/// Converts the ISBN-10 to ISBN-13 without hyphens, valid ISBN-13 only get their hyphens
+/// removed. Returns `None` if the value is not a valid ISBN.
+#[must_use]
+pub fn isbn10_to_isbn13<'a, T>(val: T) -> Option<String>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    if validate_isbn13(val.as_ref()) {
+        return Some(compact(&val));
+    }
+
+    if !validate_isbn10(val.as_ref()) {
+        return None;
+    }
+
+    let body = format!("978{}", &compact(&val)[..9]);
+    let check = gtin_check_digit(&body);
+
+    Some(format!("{}{}", body, check))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        isbn10_to_isbn13, validate_ean13, validate_ean8, validate_gtin, validate_gtin14,
+        validate_isbn, validate_isbn10, validate_isbn13, validate_issn, validate_upc_a,
+    };
+
+    #[test]
+    fn test_validate_isbn() {
+        let tests = vec![
+            ("0306406152", true),
+            ("0-306-40615-2", true),
+            ("080442957X", true),
+            ("080442957x", true),
+            ("9780306406157", true),
+            ("978-0-306-40615-7", true),
+            ("979-10-90636-07-1", true),
+            ("0306406153", false),
+            ("9780306406158", false),
+            ("1234567890123", false),
+            ("03064061X2", false),
+            ("12345678é", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_isbn(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_isbn_cow() {
+        let test: Cow<'static, str> = "978-0-306-40615-7".into();
+        assert!(validate_isbn(test));
+        let test: Cow<'static, str> = String::from("978-0-306-40615-7").into();
+        assert!(validate_isbn(test));
+        let test: Cow<'static, str> = "978-0-306-40615-8".into();
+        assert!(!validate_isbn(test));
+        let test: Cow<'static, str> = String::from("978-0-306-40615-8").into();
+        assert!(!validate_isbn(test));
+    }
+
+    #[test]
+    fn test_validate_isbn10_isbn13() {
+        assert!(validate_isbn10("0-306-40615-2"));
+        assert!(!validate_isbn10("978-0-306-40615-7"));
+        assert!(validate_isbn13("978-0-306-40615-7"));
+        assert!(!validate_isbn13("0-306-40615-2"));
+    }
+
+    #[test]
+    fn test_validate_issn() {
+        let tests = vec![
+            ("0378-5955", true),
+            ("03785955", true),
+            ("2049-3630", true),
+            ("0000-006X", true),
+            ("0378-5956", false),
+            ("037-85955", false),
+            ("0378 5955", false),
+            ("123456é", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_issn(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_barcodes() {
+        assert!(validate_ean8("96385074"));
+        assert!(!validate_ean8("96385075"));
+        assert!(validate_ean13("4006381333931"));
+        assert!(!validate_ean13("4006381333932"));
+        assert!(validate_upc_a("036000291452"));
+        assert!(!validate_upc_a("036000291453"));
+        assert!(validate_gtin14("10012345000017"));
+        assert!(!validate_gtin14("10012345000018"));
+        assert!(validate_gtin("96385074"));
+        assert!(validate_gtin("036000291452"));
+        assert!(validate_gtin("4006381333931"));
+        assert!(validate_gtin("10012345000017"));
+        assert!(!validate_gtin("400638133393"));
+        assert!(!validate_gtin("4006-3813-3393-1"));
+    }
+
+    #[test]
+    fn test_isbn10_to_isbn13() {
+        let tests = vec![
+            ("0-306-40615-2", Some("9780306406157")),
+            ("080442957X", Some("9780804429573")),
+            ("978-0-306-40615-7", Some("9780306406157")),
+            ("0-306-40615-3", None),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(isbn10_to_isbn13(input), expected.map(|e| e.to_string()));
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use semver::Version;
+
+pub use semver::VersionReq;
+use std::borrow::Cow;
+
+/// Validates whether the given string is a semantic version, e.g. `1.2.3` or `1.0.0-beta.1`
+#[must_use]
+pub fn validate_semver<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    Version::parse(val.into().as_ref()).is_ok()
+}
+
+/// Parses the version requirement, e.g. `>=1.2` or `^2, <2.5`
+///
+/// # Panics
+///
+/// When the requirement itself is not valid.
+#[must_use]
+pub fn parse_semver_req(req: &str) -> VersionReq {
+    VersionReq::parse(req).unwrap_or_else(|_| panic!("Invalid semver requirement `{}`", req))
+}
+
+/// Validates whether the given string is a semantic version matching the requirement.
+/// Pre-release versions only match requirements that mention a pre-release of the
+/// same version.
+#[must_use]
+pub fn validate_semver_req<'a, T>(val: T, req: &VersionReq) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    Version::parse(val.into().as_ref()).is_ok_and(|version| req.matches(&version))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{parse_semver_req, validate_semver, validate_semver_req};
+
+    #[test]
+    fn test_validate_semver() {
+        let tests = vec![
+            ("0.0.0", true),
+            ("1.2.3", true),
+            ("1.0.0-beta.1", true),
+            ("1.0.0+build.5", true),
+            ("10.20.30-rc.1+sha.5114f85", true),
+            ("1.2", false),
+            ("v1.2.3", false),
+            ("01.2.3", false),
+            ("1.2.3-", false),
+            ("", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_semver(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_semver_cow() {
+        let test: Cow<'static, str> = "1.2.3".into();
+        assert!(validate_semver(test));
+        let test: Cow<'static, str> = String::from("1.2.3").into();
+        assert!(validate_semver(test));
+        let test: Cow<'static, str> = "1.2".into();
+        assert!(!validate_semver(test));
+        let test: Cow<'static, str> = String::from("1.2").into();
+        assert!(!validate_semver(test));
+    }
+
+    #[test]
+    fn test_validate_semver_req() {
+        let tests = vec![
+            ("1.2.0", ">=1.2", true),
+            ("2.0.0", ">=1.2", true),
+            ("1.1.9", ">=1.2", false),
+            ("2.4.1", "^2, <2.5", true),
+            ("2.5.0", "^2, <2.5", false),
+            ("1.3.0-beta.1", ">=1.2", false),
+            ("1.2", ">=1.2", false),
+        ];
+
+        for (input, req, expected) in tests {
+            assert_eq!(
+                validate_semver_req(input, &parse_semver_req(req)),
+                expected,
+                "{} {}",
+                input,
+                req
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid semver requirement `>>1`")]
+    fn test_parse_semver_req_invalid() {
+        let _ = parse_semver_req(">>1");
+    }
+}
@@ -0,0 +1,122 @@
+use deunicode::deunicode;
+use std::borrow::Cow;
+
+/// Validates whether the given string is a lowercase slug with the given separator,
+/// e.g. `my-first-post` for `-`. Separators can't be repeated or at either end.
+#[must_use]
+pub fn validate_slug_with<'a, T>(val: T, separator: char) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().split(separator).all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Validates whether the given string is a lowercase kebab case slug, e.g. `my-first-post`
+#[must_use]
+pub fn validate_slug<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    validate_slug_with(val, '-')
+}
+
+/// Turns the string into a lowercase kebab case slug, transliterating it to ASCII first,
+/// e.g. `Čaj & kava: 10 savjeta` becomes `caj-kava-10-savjeta`
+#[must_use]
+pub fn slugify<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    deunicode(&val.into())
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{slugify, validate_slug, validate_slug_with};
+
+    #[test]
+    fn test_validate_slug() {
+        let tests = vec![
+            ("post", true),
+            ("my-first-post", true),
+            ("top-10-tips", true),
+            ("2024", true),
+            ("", false),
+            ("My-Post", false),
+            ("my--post", false),
+            ("-my-post", false),
+            ("my-post-", false),
+            ("my_post", false),
+            ("my post", false),
+            ("čevapi", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_slug(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_slug_cow() {
+        let test: Cow<'static, str> = "my-post".into();
+        assert!(validate_slug(test));
+        let test: Cow<'static, str> = String::from("my-post").into();
+        assert!(validate_slug(test));
+        let test: Cow<'static, str> = "My Post".into();
+        assert!(!validate_slug(test));
+        let test: Cow<'static, str> = String::from("My Post").into();
+        assert!(!validate_slug(test));
+    }
+
+    #[test]
+    fn test_validate_slug_with() {
+        let tests = vec![
+            ("my_first_post", '_', true),
+            ("my-first-post", '_', false),
+            ("my__post", '_', false),
+            ("my.post", '.', true),
+        ];
+
+        for (input, separator, expected) in tests {
+            assert_eq!(validate_slug_with(input, separator), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_slugify() {
+        let tests = vec![
+            ("My First Post", "my-first-post"),
+            ("Čaj & kava: 10 savjeta", "caj-kava-10-savjeta"),
+            ("  --Hello,   World!--  ", "hello-world"),
+            ("Größe über alles", "grosse-uber-alles"),
+            ("Москва", "moskva"),
+            ("already-a-slug", "already-a-slug"),
+            ("!!!", ""),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(slugify(input), expected, "{}", input);
+            assert!(expected.is_empty() || validate_slug(slugify(input)));
+        }
+    }
+
+    #[test]
+    fn test_slugify_cow() {
+        let test: Cow<'static, str> = "Hello World".into();
+        assert_eq!(slugify(test), "hello-world");
+        let test: Cow<'static, str> = String::from("Hello World").into();
+        assert_eq!(slugify(test), "hello-world");
+    }
+}
@@ -0,0 +1,95 @@
+use super::port::validate_port;
+use idna::domain_to_ascii;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::borrow::Cow;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+lazy_static! {
+    // RFC 1123 host name, labels of up to 63 characters separated by dots
+    static ref HOSTNAME_RE: Regex = Regex::new(
+        r"(?i)^[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?(?:\.[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?)*$"
+    ).unwrap();
+}
+
+/// Validates whether the given string is a `host:port` pair, where the host
+/// is an IP V4 address, a bracketed IP V6 address or a host name
+#[must_use]
+pub fn validate_socket_address<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    if SocketAddr::from_str(&val).is_ok() {
+        return validate_port(val.rsplit(':').next().unwrap_or_default());
+    }
+
+    match val.rsplit_once(':') {
+        Some((host, port)) => validate_hostname(host) && validate_port(port),
+        None => false,
+    }
+}
+
+/// Checks if the host is a valid host name, including internationalized ones
+#[must_use]
+fn validate_hostname(host: &str) -> bool {
+    if host.len() > 253 || host.contains(':') {
+        return false;
+    }
+
+    if HOSTNAME_RE.is_match(host) {
+        return true;
+    }
+
+    match domain_to_ascii(host) {
+        Ok(d) => d.len() <= 253 && HOSTNAME_RE.is_match(&d),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_socket_address;
+
+    #[test]
+    fn test_validate_socket_address() {
+        let tests = vec![
+            ("127.0.0.1:8080", true),
+            ("[::1]:443", true),
+            ("[fe80::223:6cff:fe8a:2e8a]:22", true),
+            ("localhost:5432", true),
+            ("db-1.internal.example.com:3306", true),
+            ("bücher.example:80", true),
+            ("127.0.0.1:0", false),
+            ("127.0.0.1:65536", false),
+            ("127.0.0.1", false),
+            ("::1:443", false),
+            ("localhost", false),
+            ("localhost:", false),
+            (":80", false),
+            ("-invalid.com:80", false),
+            ("exam_ple.com:80", false),
+            ("localhost:http", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_socket_address(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_socket_address_cow() {
+        let test: Cow<'static, str> = "localhost:5432".into();
+        assert!(validate_socket_address(test));
+        let test: Cow<'static, str> = String::from("localhost:5432").into();
+        assert!(validate_socket_address(test));
+        let test: Cow<'static, str> = "localhost".into();
+        assert!(!validate_socket_address(test));
+        let test: Cow<'static, str> = String::from("localhost").into();
+        assert!(!validate_socket_address(test));
+    }
+}
@@ -0,0 +1,595 @@
+use std::borrow::Cow;
+
+/// Validates whether the given value is a VAT number of the given EU country,
+/// checking its format and, where one is defined, its checksum.
+///
+/// The value may be given with or without the country prefix (`HR`, or `EL` for Greece),
+/// spaces, dots and dashes are ignored. Unknown countries are never valid.
+///
+/// Every country is checked against its check digit, except for NL, which is checked for
+/// its format only. French numbers with letters in the key, Czech birth numbers issued
+/// before 1954 and Latvian personal codes issued since 2017 have no check digit, only their
+/// format and, for the Czech birth numbers, the date are checked.
+#[must_use]
+pub fn validate_vat<'a, T, V>(country: T, value: V) -> bool
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    let country = country.into().trim().to_uppercase();
+    let prefix = match country.as_str() {
+        "GR" => "EL",
+        c => c,
+    };
+
+    let value = value
+        .into()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '.' && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+    let value = value.strip_prefix(prefix).unwrap_or(&value);
+
+    if !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return false;
+    }
+
+    match prefix {
+        "AT" => vat_at(value),
+        "BE" => vat_be(value),
+        "BG" => vat_bg(value),
+        "CY" => vat_cy(value),
+        "CZ" => vat_cz(value),
+        "DE" => is_digits(value, 9) && !value.starts_with('0') && mod_11_10(value),
+        "DK" => is_digits(value, 8) && weighted_sum(value, &[2, 7, 6, 5, 4, 3, 2, 1]) % 11 == 0,
+        "EE" => vat_ee(value),
+        "EL" => vat_el(value),
+        "ES" => vat_es(value),
+        "FI" => vat_fi(value),
+        "FR" => vat_fr(value),
+        "HR" => is_digits(value, 11) && mod_11_10(value),
+        "HU" => is_digits(value, 8) && weighted_sum(value, &[9, 7, 3, 1, 9, 7, 3, 1]) % 10 == 0,
+        "IE" => vat_ie(value),
+        "IT" => is_digits(value, 11) && luhn(value),
+        "LT" => vat_lt(value),
+        "LU" => vat_lu(value),
+        "LV" => vat_lv(value),
+        "MT" => vat_mt(value),
+        "NL" => vat_nl(value),
+        "PL" => vat_pl(value),
+        "PT" => vat_pt(value),
+        "RO" => vat_ro(value),
+        "SE" => is_digits(value, 12) && value.ends_with("01") && luhn(&value[..10]),
+        "SI" => vat_si(value),
+        "SK" => is_digits(value, 10) && value.parse::<u64>().is_ok_and(|v| v % 11 == 0),
+        _ => false,
+    }
+}
+
+fn is_digits(value: &str, len: usize) -> bool {
+    value.len() == len && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn digits(value: &str) -> Vec<u32> {
+    value.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn weighted_sum(value: &str, weights: &[u32]) -> u32 {
+    digits(value).iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// ISO 7064 MOD 11,10 check digit, used by DE and HR (OIB)
+fn mod_11_10(value: &str) -> bool {
+    let digits = digits(value);
+    let (check, body) = digits.split_last().unwrap_or((&0, &[]));
+
+    let product = body.iter().fold(10, |product, d| {
+        let sum = (d + product) % 10;
+        let sum = if sum == 0 { 10 } else { sum };
+
+        (sum * 2) % 11
+    });
+
+    (11 - product) % 10 == *check
+}
+
+fn luhn(value: &str) -> bool {
+    let sum: u32 = digits(value)
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| match (i % 2 == 1, d * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => *d,
+        })
+        .sum();
+
+    sum % 10 == 0
+}
+
+fn vat_at(value: &str) -> bool {
+    let number = match value.strip_prefix('U') {
+        Some(number) if is_digits(number, 8) => number,
+        _ => return false,
+    };
+
+    let digits = digits(number);
+    let sum: u32 = digits[..7]
+        .iter()
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 1 {
+                d * 2 / 10 + d * 2 % 10
+            } else {
+                *d
+            }
+        })
+        .sum();
+
+    (10 - (sum + 4) % 10) % 10 == digits[7]
+}
+
+fn vat_be(value: &str) -> bool {
+    let value = if value.len() == 9 {
+        format!("0{}", value)
+    } else {
+        value.to_string()
+    };
+
+    if !is_digits(&value, 10) || !(value.starts_with('0') || value.starts_with('1')) {
+        return false;
+    }
+
+    match (value[..8].parse::<u32>(), value[8..].parse::<u32>()) {
+        (Ok(number), Ok(check)) => 97 - number % 97 == check,
+        _ => false,
+    }
+}
+
+fn vat_bg(value: &str) -> bool {
+    let digits = digits(value);
+
+    if is_digits(value, 9) {
+        let check = match weighted_sum(value, &[1, 2, 3, 4, 5, 6, 7, 8]) % 11 {
+            10 => weighted_sum(value, &[3, 4, 5, 6, 7, 8, 9, 10]) % 11 % 10,
+            check => check,
+        };
+
+        return check == digits[8];
+    }
+
+    if !is_digits(value, 10) {
+        return false;
+    }
+
+    // Personal number (EGN), foreigner number (PNF) or any other taxpayer
+    let egn = weighted_sum(value, &[2, 4, 8, 5, 10, 9, 7, 3, 6]) % 11 % 10;
+    let pnf = weighted_sum(value, &[21, 19, 17, 13, 11, 9, 7, 3, 1]) % 10;
+    let other = match 11 - weighted_sum(value, &[4, 3, 2, 7, 6, 5, 4, 3, 2]) % 11 {
+        11 => Some(0),
+        10 => None,
+        check => Some(check),
+    };
+
+    egn == digits[9] || pnf == digits[9] || other == Some(digits[9])
+}
+
+fn vat_cy(value: &str) -> bool {
+    if value.len() != 9 || !is_digits(&value[..8], 8) || value.starts_with("12") {
+        return false;
+    }
+
+    let sum: u32 = digits(&value[..8])
+        .iter()
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 0 {
+                [1, 0, 5, 7, 9, 13, 15, 17, 19, 21][*d as usize]
+            } else {
+                *d
+            }
+        })
+        .sum();
+
+    value.as_bytes()[8] == b'A' + (sum % 26) as u8
+}
+
+fn vat_cz(value: &str) -> bool {
+    let digits = digits(value);
+
+    match value.len() {
+        // Legal entity
+        8 if is_digits(value, 8) && !value.starts_with('9') => {
+            let check = (11 - weighted_sum(value, &[8, 7, 6, 5, 4, 3, 2]) % 11) % 11;
+            let check = if check == 0 { 1 } else { check % 10 };
+
+            check == digits[7]
+        }
+        // Individual without a birth number
+        9 if is_digits(value, 9) && value.starts_with('6') => {
+            let check = weighted_sum(&value[1..], &[8, 7, 6, 5, 4, 3, 2]) % 11;
+
+            9 - (11 - check) % 10 == digits[8]
+        }
+        // Birth numbers issued before 1954 have no check digit, only the date is checked
+        9 => is_digits(value, 9) && birth_date_before_1954(value),
+        10 if is_digits(value, 10) => match value.parse::<u64>() {
+            Ok(number) => number % 11 == 0 || (number / 10 % 11 == 10 && digits[9] == 0),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks the `YYMMDD` date of a Czech birth number issued before 1954,
+/// 50 is added to the month for women
+fn birth_date_before_1954(value: &str) -> bool {
+    let digits = digits(value);
+    let year = digits[0] * 10 + digits[1];
+    let month = (digits[2] * 10 + digits[3]) % 50;
+    let day = digits[4] * 10 + digits[5];
+
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year > 0 && year % 4 == 0 => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    year < 54 && (1..=days).contains(&day)
+}
+
+fn vat_ee(value: &str) -> bool {
+    if !is_digits(value, 9) {
+        return false;
+    }
+
+    let check = (10 - weighted_sum(value, &[3, 7, 1, 3, 7, 1, 3, 7]) % 10) % 10;
+
+    check == digits(value)[8]
+}
+
+fn vat_el(value: &str) -> bool {
+    if !is_digits(value, 9) {
+        return false;
+    }
+
+    let check = weighted_sum(value, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10;
+
+    check == digits(value)[8]
+}
+
+fn vat_es(value: &str) -> bool {
+    const NIF_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+    if value.len() != 9 || !is_digits(&value[1..8], 7) {
+        return false;
+    }
+
+    let bytes = value.as_bytes();
+    let (first, last) = (bytes[0], bytes[8]);
+    let nif_letter = |number: &str| number.parse::<usize>().map(|n| NIF_LETTERS[n % 23]);
+
+    match first {
+        // Individual, DNI
+        b'0'..=b'9' => nif_letter(&value[..8]) == Ok(last),
+        // Foreigner, NIE, the first letter stands for 0, 1 or 2
+        b'X' | b'Y' | b'Z' => nif_letter(&format!("{}{}", first - b'X', &value[1..8])) == Ok(last),
+        // Individual without a DNI
+        b'K' | b'L' | b'M' => nif_letter(&value[1..8]) == Ok(last),
+        // Legal entity, CIF, the control character is a digit or a letter depending on the type
+        b'A'..=b'H' | b'J' | b'N' | b'P'..=b'S' | b'U'..=b'W' => {
+            let sum: u32 = digits(&value[1..8])
+                .iter()
+                .enumerate()
+                .map(|(i, d)| {
+                    if i % 2 == 0 {
+                        d * 2 / 10 + d * 2 % 10
+                    } else {
+                        *d
+                    }
+                })
+                .sum();
+            let check = ((10 - sum % 10) % 10) as u8;
+            let digit = last == b'0' + check;
+            let letter = last == b"JABCDEFGHI"[check as usize];
+
+            match first {
+                b'A' | b'B' | b'E' | b'H' => digit,
+                b'N' | b'P' | b'Q' | b'R' | b'S' | b'W' => letter,
+                _ => digit || letter,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn vat_fi(value: &str) -> bool {
+    if !is_digits(value, 8) {
+        return false;
+    }
+
+    let remainder = weighted_sum(value, &[7, 9, 10, 5, 8, 4, 2]) % 11;
+
+    match remainder {
+        0 => digits(value)[7] == 0,
+        1 => false,
+        r => 11 - r == digits(value)[7],
+    }
+}
+
+fn vat_fr(value: &str) -> bool {
+    if value.len() != 11 || !is_digits(&value[2..], 9) {
+        return false;
+    }
+
+    match (value[..2].parse::<u64>(), value[2..].parse::<u64>()) {
+        (Ok(key), Ok(siren)) => (12 + 3 * (siren % 97)) % 97 == key,
+        // Keys containing letters are issued to new companies and have no published checksum
+        _ => true,
+    }
+}
+
+fn vat_ie(value: &str) -> bool {
+    const LETTERS: &[u8] = b"WABCDEFGHIJKLMNOPQRSTUV";
+
+    let bytes = value.as_bytes();
+
+    if (bytes.len() != 8 && bytes.len() != 9)
+        || !bytes[0].is_ascii_digit()
+        || !is_digits(&value[2..7], 5)
+        || !bytes[7..].iter().all(|c| LETTERS.contains(c))
+    {
+        return false;
+    }
+
+    // Old numbers have a letter in the second place, the first digit moves to the end
+    let (number, extra) = if bytes[1].is_ascii_digit() {
+        (value[..7].to_string(), bytes.get(8))
+    } else if bytes.len() == 8 {
+        (format!("0{}{}", &value[2..7], &value[..1]), None)
+    } else {
+        return false;
+    };
+
+    let extra = extra.map_or(0, |c| {
+        LETTERS.iter().position(|l| l == c).unwrap_or(0) as u32
+    });
+    let sum = weighted_sum(&number, &[8, 7, 6, 5, 4, 3, 2]) + extra * 9;
+
+    bytes[7] == LETTERS[(sum % 23) as usize]
+}
+
+fn vat_lt(value: &str) -> bool {
+    let len = value.len();
+
+    if !(is_digits(value, 9) || is_digits(value, 12)) || &value[len - 2..len - 1] != "1" {
+        return false;
+    }
+
+    let body = &value[..len - 1];
+    let check = match weighted_sum(body, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 2]) % 11 {
+        10 => weighted_sum(body, &[3, 4, 5, 6, 7, 8, 9, 1, 2, 3, 4]) % 11 % 10,
+        check => check,
+    };
+
+    check == digits(value)[len - 1]
+}
+
+fn vat_lu(value: &str) -> bool {
+    if !is_digits(value, 8) {
+        return false;
+    }
+
+    match (value[..6].parse::<u32>(), value[6..].parse::<u32>()) {
+        (Ok(number), Ok(check)) => number % 89 == check,
+        _ => false,
+    }
+}
+
+fn vat_lv(value: &str) -> bool {
+    if !is_digits(value, 11) {
+        return false;
+    }
+
+    match digits(value)[0] {
+        // Legal entity
+        4..=9 => weighted_sum(value, &[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1]) % 11 == 3,
+        // Personal codes issued since 2017 have no check digit
+        3 if value.starts_with("32") => true,
+        _ => {
+            let check = (1 + weighted_sum(value, &[10, 5, 8, 4, 2, 1, 6, 3, 7, 9])) % 11 % 10;
+
+            check == digits(value)[10]
+        }
+    }
+}
+
+fn vat_mt(value: &str) -> bool {
+    is_digits(value, 8)
+        && !value.starts_with('0')
+        && weighted_sum(value, &[3, 4, 6, 7, 8, 9, 10, 1]) % 37 == 0
+}
+
+fn vat_nl(value: &str) -> bool {
+    value.len() == 12
+        && is_digits(&value[..9], 9)
+        && &value[9..10] == "B"
+        && is_digits(&value[10..], 2)
+}
+
+fn vat_pl(value: &str) -> bool {
+    if !is_digits(value, 10) {
+        return false;
+    }
+
+    let check = weighted_sum(value, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11;
+
+    check != 10 && check == digits(value)[9]
+}
+
+fn vat_pt(value: &str) -> bool {
+    if !is_digits(value, 9) {
+        return false;
+    }
+
+    let check = match 11 - weighted_sum(value, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 {
+        check if check > 9 => 0,
+        check => check,
+    };
+
+    check == digits(value)[8]
+}
+
+fn vat_ro(value: &str) -> bool {
+    if !(2..=10).any(|len| is_digits(value, len)) || value.starts_with('0') {
+        return false;
+    }
+
+    // Shorter numbers are padded with zeros on the left to 10 digits
+    let padded = format!("{:0>10}", value);
+    let check = 10 * weighted_sum(&padded, &[7, 5, 3, 2, 1, 7, 5, 3, 2]) % 11 % 10;
+
+    check == digits(value)[value.len() - 1]
+}
+
+fn vat_si(value: &str) -> bool {
+    if !is_digits(value, 8) || value.starts_with('0') {
+        return false;
+    }
+
+    match 11 - weighted_sum(value, &[8, 7, 6, 5, 4, 3, 2]) % 11 {
+        11 => false,
+        10 => digits(value)[7] == 0,
+        check => check == digits(value)[7],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::validate_vat;
+
+    #[test]
+    fn test_validate_vat() {
+        let tests = vec![
+            ("AT", "ATU13585627", true),
+            ("AT", "U13585626", false),
+            ("BE", "BE0428759497", true),
+            ("BE", "0428759498", false),
+            ("BG", "BG175074752", true),
+            ("BG", "175074753", false),
+            ("BG", "7523169263", true),
+            ("BG", "7523169264", false),
+            ("CY", "CY10259033P", true),
+            ("CY", "10259033Q", false),
+            ("CY", "12000139V", false),
+            ("CZ", "CZ25123891", true),
+            ("CZ", "25123890", false),
+            ("CZ", "640903926", true),
+            ("CZ", "640903927", false),
+            ("CZ", "7103192745", true),
+            ("CZ", "7103192746", false),
+            ("CZ", "530101123", true),
+            ("CZ", "525229123", true),
+            ("CZ", "535229123", false),
+            ("CZ", "531301123", false),
+            ("CZ", "530132123", false),
+            ("CZ", "540101123", false),
+            ("DE", "DE136695976", true),
+            ("DE", "136695977", false),
+            ("DK", "DK13585628", true),
+            ("DK", "13585627", false),
+            ("EE", "EE100931558", true),
+            ("EE", "100931559", false),
+            ("GR", "EL094259216", true),
+            ("GR", "094259217", false),
+            ("ES", "ESA28015865", true),
+            ("ES", "A2801586", false),
+            ("ES", "ESA28015866", false),
+            ("ES", "B65410011", true),
+            ("ES", "Q2826000H", true),
+            ("ES", "Q28260008", false),
+            ("ES", "12345678Z", true),
+            ("ES", "12345678A", false),
+            ("ES", "X1234567L", true),
+            ("ES", "Y1234567X", true),
+            ("ES", "X1234567X", false),
+            ("ES", "K0867756N", true),
+            ("ES", "I1234567J", false),
+            ("FI", "FI20774740", true),
+            ("FI", "20774741", false),
+            ("FR", "FR40303265045", true),
+            ("FR", "41303265045", false),
+            ("HR", "HR69435151530", true),
+            ("hr", "69435151531", false),
+            ("HU", "HU12892312", true),
+            ("HU", "12892313", false),
+            ("IE", "IE6433435F", true),
+            ("IE", "IE6433435OA", true),
+            ("IE", "643343F", false),
+            ("IE", "IE6433435E", false),
+            ("IE", "IE6433435OB", false),
+            ("IE", "8D79739I", true),
+            ("IE", "8D79739J", false),
+            ("IE", "6388047V", true),
+            ("IE", "3628739UA", true),
+            ("IT", "IT00743110157", true),
+            ("IT", "00743110158", false),
+            ("LT", "LT119511515", true),
+            ("LT", "119511516", false),
+            ("LT", "LT100001919017", true),
+            ("LT", "100001919018", false),
+            ("LU", "LU26375245", true),
+            ("LU", "26375246", false),
+            ("LV", "LV40003521600", true),
+            ("LV", "40003521601", false),
+            ("LV", "16117519997", true),
+            ("LV", "16117519998", false),
+            ("MT", "MT11679112", true),
+            ("MT", "11679113", false),
+            ("NL", "NL004495445B01", true),
+            ("NL", "004495445C01", false),
+            ("PL", "PL5260250274", true),
+            ("PL", "5260250275", false),
+            ("PT", "PT501964843", true),
+            ("PT", "501964844", false),
+            ("RO", "RO18547290", true),
+            ("RO", "18547291", false),
+            ("SE", "SE556188840401", true),
+            ("SE", "556188840402", false),
+            ("SI", "SI50223054", true),
+            ("SI", "50223055", false),
+            ("SK", "SK2022749619", true),
+            ("SK", "2022749618", false),
+            ("DE", "DE 136.695-976", true),
+            ("DE", "HR69435151530", false),
+            ("XX", "12345678", false),
+        ];
+
+        for (country, input, expected) in tests {
+            assert_eq!(
+                validate_vat(country, input),
+                expected,
+                "{} {}",
+                country,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_vat_cow() {
+        let test: Cow<'static, str> = "HR69435151530".into();
+        assert!(validate_vat("HR", test));
+        let test: Cow<'static, str> = String::from("HR69435151530").into();
+        assert!(validate_vat("HR", test));
+        let test: Cow<'static, str> = "HR69435151531".into();
+        assert!(!validate_vat("HR", test));
+        let test: Cow<'static, str> = String::from("HR69435151531").into();
+        assert!(!validate_vat("HR", test));
+    }
+}
@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+use unic_ucd_common::control;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Characters without a width that are commonly pasted along with text. The zero width
+/// joiner and non-joiner are kept, emoji sequences and scripts such as Persian need them.
+const ZERO_WIDTH_CHARACTERS: [char; 3] = [
+    '\u{200B}', // zero width space
+    '\u{2060}', // word joiner
+    '\u{FEFF}', // zero width no-break space, byte order mark
+];
+
+/// Replaces every run of whitespace, including tabs and newlines, with a single space
+#[must_use]
+pub fn collapse_whitespace<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+    let mut result = String::with_capacity(val.len());
+    let mut previous_whitespace = false;
+
+    for c in val.chars() {
+        if c.is_whitespace() {
+            if !previous_whitespace {
+                result.push(' ');
+            }
+
+            previous_whitespace = true;
+        } else {
+            result.push(c);
+            previous_whitespace = false;
+        }
+    }
+
+    result
+}
+
+/// Removes control characters, tabs and newlines are kept
+#[must_use]
+pub fn strip_control_characters<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into()
+        .chars()
+        .filter(|c| matches!(c, '\t' | '\n' | '\r') || !control::is_control(*c))
+        .collect()
+}
+
+/// Removes zero width spaces, word joiners and byte order marks
+#[must_use]
+pub fn remove_zero_width<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into()
+        .chars()
+        .filter(|c| !ZERO_WIDTH_CHARACTERS.contains(c))
+        .collect()
+}
+
+/// Converts Windows (`\r\n`) and old Mac (`\r`) newlines to `\n`
+#[must_use]
+pub fn normalize_newlines<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Keeps at most `max` characters, never splits a character
+#[must_use]
+pub fn truncate_chars<'a, T>(val: T, max: usize) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    match val.char_indices().nth(max) {
+        Some((end, _)) => val[..end].to_string(),
+        None => val.into_owned(),
+    }
+}
+
+/// Keeps at most `max` user-perceived characters, so letters with combining accents,
+/// flags and emoji sequences are never split
+#[must_use]
+pub fn truncate_graphemes<'a, T>(val: T, max: usize) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    match val.grapheme_indices(true).nth(max) {
+        Some((end, _)) => val[..end].to_string(),
+        None => val.into_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        collapse_whitespace, normalize_newlines, remove_zero_width, strip_control_characters,
+        truncate_chars, truncate_graphemes,
+    };
+
+    #[test]
+    fn test_collapse_whitespace() {
+        let tests = vec![
+            ("hello world", "hello world"),
+            ("hello   world", "hello world"),
+            ("hello\t\n world", "hello world"),
+            ("  hello  ", " hello "),
+            ("hello\u{00A0}\u{3000}world", "hello world"),
+            ("", ""),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(collapse_whitespace(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_collapse_whitespace_cow() {
+        let test: Cow<'static, str> = "a  b".into();
+        assert_eq!(collapse_whitespace(test), "a b");
+        let test: Cow<'static, str> = String::from("a  b").into();
+        assert_eq!(collapse_whitespace(test), "a b");
+    }
+
+    #[test]
+    fn test_strip_control_characters() {
+        let tests = vec![
+            ("hello", "hello"),
+            ("hel\u{0000}lo", "hello"),
+            ("hello\u{001B}[31m", "hello[31m"),
+            ("line\u{0085}break", "linebreak"),
+            ("tab\tand\r\nnewline", "tab\tand\r\nnewline"),
+            ("небо", "небо"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(strip_control_characters(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_remove_zero_width() {
+        let tests = vec![
+            ("hello", "hello"),
+            ("hel\u{200B}lo", "hello"),
+            ("\u{FEFF}hello", "hello"),
+            ("a\u{200B}b\u{2060}c\u{FEFF}d", "abcd"),
+            // family emoji, zero width joiners between the people
+            (
+                "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+                "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            ),
+            // persian "mi-khaham", zero width non-joiner after the prefix
+            (
+                "\u{0645}\u{06CC}\u{200C}\u{062E}\u{0648}\u{0627}\u{0647}\u{0645}",
+                "\u{0645}\u{06CC}\u{200C}\u{062E}\u{0648}\u{0627}\u{0647}\u{0645}",
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(remove_zero_width(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_normalize_newlines() {
+        let tests = vec![
+            ("a\nb", "a\nb"),
+            ("a\r\nb", "a\nb"),
+            ("a\rb", "a\nb"),
+            ("a\r\n\r\nb\r", "a\n\nb\n"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(normalize_newlines(input), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_truncate() {
+        let tests = vec![
+            // (input, max, chars, graphemes)
+            ("hello", 3, "hel", "hel"),
+            ("hello", 10, "hello", "hello"),
+            ("hello", 0, "", ""),
+            ("čćžšđ", 2, "čć", "čć"),
+            ("cafe\u{0301}s", 4, "cafe", "cafe\u{0301}"),
+            ("🇭🇷🇩🇪", 1, "🇭", "🇭🇷"),
+            ("👨‍👩‍👧 family", 1, "👨", "👨‍👩‍👧"),
+        ];
+
+        for (input, max, chars, graphemes) in tests {
+            assert_eq!(truncate_chars(input, max), chars, "{}", input);
+            assert_eq!(truncate_graphemes(input, max), graphemes, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_truncate_cow() {
+        let test: Cow<'static, str> = "hello".into();
+        assert_eq!(truncate_chars(test, 2), "he");
+        let test: Cow<'static, str> = String::from("hello").into();
+        assert_eq!(truncate_graphemes(test, 2), "he");
+    }
+}
@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::{skeleton, MixedScript};
+
+/// Unicode normalization form, see <https://unicode.org/reports/tr15/>
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormalizationForm {
+    /// Canonical composition, e.g. `e` followed by a combining acute accent becomes `é`
+    Nfc,
+    /// Canonical decomposition, e.g. `é` becomes `e` followed by a combining acute accent
+    Nfd,
+    /// Compatibility composition, also turns full-width and other compatibility characters
+    /// into their plain form, e.g. `ｆｏｏ` becomes `foo` and `ﬁ` becomes `fi`
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// Normalizes the string to the given normalization form
+#[must_use]
+pub fn normalize<'a, T>(val: T, form: NormalizationForm) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let val = val.into();
+
+    match form {
+        NormalizationForm::Nfc => val.nfc().collect(),
+        NormalizationForm::Nfd => val.nfd().collect(),
+        NormalizationForm::Nfkc => val.nfkc().collect(),
+        NormalizationForm::Nfkd => val.nfkd().collect(),
+    }
+}
+
+/// Folds the case for caseless comparison, unlike lowercasing this also maps e.g.
+/// `ß` to `ss` and the final `ς` to `σ`. Uses the Unicode default full case folding,
+/// Turkic dotless `ı` is kept as it is.
+#[must_use]
+pub fn case_fold<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    caseless::default_case_fold_str(&val.into())
+}
+
+/// Validates whether all the characters belong to the same script, e.g. it rejects Latin
+/// mixed with Cyrillic look-alikes such as `pаypal`. Digits, punctuation and combinations
+/// that are written together, like Han with Hiragana, are allowed.
+#[must_use]
+pub fn validate_single_script<'a, T>(val: T) -> bool
+where
+    T: Into<Cow<'a, str>>,
+{
+    val.into().as_ref().is_single_script()
+}
+
+/// Key for comparing strings that look the same, two strings are confusable when their
+/// skeletons are equal. Compatibility characters and case are folded first.
+#[must_use]
+pub fn confusable_skeleton<'a, T>(val: T) -> String
+where
+    T: Into<Cow<'a, str>>,
+{
+    let folded = case_fold(normalize(val, NormalizationForm::Nfkc));
+
+    skeleton(&folded).collect()
+}
+
+/// Checks whether the two strings look the same, e.g. `paypal` and `pаypаl` with Cyrillic `а`
+#[must_use]
+pub fn is_confusable_with<'a, T, V>(val: T, other: V) -> bool
+where
+    T: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    confusable_skeleton(val) == confusable_skeleton(other)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{
+        case_fold, is_confusable_with, normalize, validate_single_script, NormalizationForm,
+    };
+
+    #[test]
+    fn test_normalize() {
+        let composed = "\u{00E9}";
+        let decomposed = "e\u{0301}";
+
+        assert_eq!(normalize(decomposed, NormalizationForm::Nfc), composed);
+        assert_eq!(normalize(composed, NormalizationForm::Nfd), decomposed);
+        assert_eq!(normalize("ｆｏｏ", NormalizationForm::Nfkc), "foo");
+        assert_eq!(normalize("ｆｏｏ", NormalizationForm::Nfc), "ｆｏｏ");
+        assert_eq!(normalize("ﬁ", NormalizationForm::Nfkd), "fi");
+    }
+
+    #[test]
+    fn test_normalize_cow() {
+        let test: Cow<'static, str> = "e\u{0301}".into();
+        assert_eq!(normalize(test, NormalizationForm::Nfc), "\u{00E9}");
+        let test: Cow<'static, str> = String::from("e\u{0301}").into();
+        assert_eq!(normalize(test, NormalizationForm::Nfc), "\u{00E9}");
+    }
+
+    #[test]
+    fn test_case_fold() {
+        let tests = vec![
+            ("Hello", "hello"),
+            ("Straße", "strasse"),
+            ("STRASSE", "strasse"),
+            ("ẞ", "ss"),
+            ("ΟΔΟΣ", "οδοσ"),
+            ("οδος", "οδοσ"),
+            ("ﬀ", "ff"),
+            ("ſ", "s"),
+            ("\u{AB70}", "\u{13A0}"),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(case_fold(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_single_script() {
+        let tests = vec![
+            ("paypal", true),
+            ("john_doe42", true),
+            ("небо", true),
+            ("東京タワー", true),
+            ("p\u{0430}ypal", false),
+            ("αlpha", false),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(validate_single_script(input), expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_is_confusable_with() {
+        let tests = vec![
+            ("paypal", "paypal", true),
+            ("paypal", "p\u{0430}yp\u{0430}l", true),
+            ("admin", "ADMIN", true),
+            ("admin", "ａｄｍｉｎ", true),
+            ("rn", "m", true),
+            ("paypal", "paypa1", true),
+            ("admin", "root", false),
+        ];
+
+        for (val, other, expected) in tests {
+            assert_eq!(
+                is_confusable_with(val, other),
+                expected,
+                "{} {}",
+                val,
+                other
+            );
+        }
+    }
+}
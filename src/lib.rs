@@ -149,6 +149,33 @@
 //! }
 //! ```
 //!
+//! Numbers given without the `+` prefix can be parsed by passing the default `region`,
+//! and accepted numbers can be restricted to some `countries` or number `types`
+//! (any variant of `validr::helpers::phone::PhoneType`). When the number belongs to
+//! a country or is of a type that is not allowed, the error code will contain the parsed
+//! value, e.g. `phone_country:FR` or `phone_type:fixed_line`. The region and countries
+//! are resolved once when the rule is built, an unknown country code panics right there.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_phone!(
+//!             field_name_on_self,
+//!             region = "HR",
+//!             countries = ["HR", "SI"],
+//!             types = [Mobile]
+//!         )]
+//!     }
+//! }
+//! ```
+//!
 //! ## Non Control Character
 //!
 //! For `Option<T: ToString + Clone>` it will check if the field is present and has no control characters
@@ -229,10 +256,14 @@
 //! }
 //! ```
 //!
-//! ## Credit card
+//! ## MAC address
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and valid CC number
-//! For `String` it will check if it's valid CC number.
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid MAC address
+//! For `String` it will check if it's valid MAC address.
+//!
+//! Colon (`00:1a:2b:3c:4d:5e`), dash (`00-1A-2B-3C-4D-5E`) and dotted Cisco (`001a.2b3c.4d5e`)
+//! formats are accepted, both for EUI-48 and EUI-64 addresses. Use `rule_mac_eui48!` or
+//! `rule_mac_eui64!` to accept only one of the address lengths.
 //!
 //! ```rust
 //! #[macro_use]
@@ -244,34 +275,37 @@
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_credit_card!(field_name_on_self)]
+//!         vec![rule_mac!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Contains
+//! ## Port
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and contains given `needle`
-//! For `String` it will check if it contains given `needle`.
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid port number (1-65535)
+//! For `String` it will check if it's valid port number.
 //!
 //! ```rust
 //! #[macro_use]
 //! use validr::*;
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
-//!     field_name_on_self: Option<String>,
+//!     field_name_on_self: Option<u16>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_contains!(field_name_on_self, "needle".to_string())]
+//!         vec![rule_port!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Equal to
+//! ## Socket address
 //!
-//! It validates if two given field names are equal.
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid `host:port` pair
+//! For `String` it will check if it's valid `host:port` pair.
+//!
+//! The host can be an IP V4 address, a bracketed IP V6 address (`[::1]:443`) or a host name.
 //!
 //! ```rust
 //! #[macro_use]
@@ -279,19 +313,19 @@
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
 //!     field_name_on_self: Option<String>,
-//!     field_name_to_compare_to_on_self: Option<String>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_equalt_to!(field_name_on_self, field_name_to_compare_to_on_self)]
+//!         vec![rule_socket_address!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Not equal to
+//! ## Credit card
 //!
-//! It validates if two given field names are not equal.
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid CC number
+//! For `String` it will check if it's valid CC number.
 //!
 //! ```rust
 //! #[macro_use]
@@ -299,20 +333,18 @@
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
 //!     field_name_on_self: Option<String>,
-//!     field_name_to_compare_to_on_self: Option<String>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_not_equalt_to!(field_name_on_self, field_name_to_compare_to_on_self)]
+//!         vec![rule_credit_card!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## In
-//!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and will match its value to haystack of values
-//! For `String` it will check if its in the haystack value
+//! Accepted card brands can be restricted by listing any variants of
+//! `validr::helpers::card::CardType`. When the card is valid but of a different brand,
+//! the error code will contain the detected brand, e.g. `credit_card_brand:amex`.
 //!
 //! ```rust
 //! #[macro_use]
@@ -324,40 +356,66 @@
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_credit_card!(field_name_on_self, brands = [Visa, MasterCard, Amex])]
+//!     }
+//! }
+//! ```
+//!
+//! ## Card expiry
+//!
+//! For a single field it will check if the field is present and valid `MM/YY` or `MM/YYYY` expiry
+//! that is not in the past. Given two fields, it will check that the month and year fields
+//! are valid and not in the past, the error will be reported for the month field.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     expiry: Option<String>,
+//!     expiry_month: Option<u8>,
+//!     expiry_year: Option<u16>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
 //!         vec![
-//!             rule_in!(field_name_on_self, vec![
-//!                 "allowed_value".to_string(),
-//!                 "another_allowed_value".to_string()
-//!             ]),
+//!             rule_card_expiry!(expiry),
+//!             rule_card_expiry!(expiry_month, expiry_year),
 //!         ]
 //!     }
 //! }
 //! ```
 //!
-//! ## length min
+//! ## CVV
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and has `min` number of chars
-//! For `String` it will check if it has `min` number of chars
+//! For `Option<T: ToString + Clone>` it will check if the field is present and has the CVV length
+//! matching the brand of the card number field, 4 digits for American Express and 3 for others.
+//! For `String` it will check if it has the CVV length matching the card brand.
 //!
 //! ```rust
 //! #[macro_use]
 //! use validr::*;
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
-//!     field_name_on_self: Option<String>,
+//!     card_number: Option<String>,
+//!     cvv: Option<String>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_length_min!(field_name_on_self, 2)]
+//!         vec![rule_cvv!(cvv, card_number)]
 //!     }
 //! }
 //! ```
 //!
-//! ## length max
+//! ## IBAN
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and has `max` number of chars
-//! For `String` it will check if it has `max` number of chars
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid IBAN
+//! For `String` it will check if it's valid IBAN.
+//!
+//! The country specific length and the mod-97 checksum are validated, spaces and lowercase
+//! letters are tolerated.
 //!
 //! ```rust
 //! #[macro_use]
@@ -369,15 +427,15 @@
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_length_max!(field_name_on_self, 15)]
+//!         vec![rule_iban!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## length equal
+//! ## BIC
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and has `eq` number of chars
-//! For `String` it will check if it has `eq` number of chars
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid BIC/SWIFT code
+//! For `String` it will check if it's valid BIC/SWIFT code.
 //!
 //! ```rust
 //! #[macro_use]
@@ -389,85 +447,154 @@
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_length_eq!(field_name_on_self, 10)]
+//!         vec![rule_bic!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## length not equal
+//! ## VAT
 //!
-//! For `Option<T: ToString + Clone>` it will check if the field is present and has `ne` number of chars
-//! For `String` it will check if it has `ne` number of chars
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid VAT number
+//! of the country read from the given country field, or of the given country code.
+//! For `String` it will check if it's valid VAT number of that country.
+//!
+//! Format and checksum are validated for EU countries (e.g. HR OIB, DE USt-IdNr, IT partita IVA),
+//! without calling VIES. The VAT number can be given with or without the country prefix.
 //!
 //! ```rust
 //! #[macro_use]
 //! use validr::*;
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
-//!     field_name_on_self: Option<String>,
+//!     vat_id: Option<String>,
+//!     country: Option<String>,
+//!     croatian_vat_id: Option<String>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_length_ne!(field_name_on_self, 11)]
+//!         vec![
+//!             rule_vat!(vat_id, country),
+//!             rule_vat!(croatian_vat_id, "HR"),
+//!         ]
 //!     }
 //! }
 //! ```
 //!
-//! ## Range
+//! ## Postal code
 //!
-//! For `Option<T: Into<f64> + PartialOrd + Clone>` it will check that the value is present and within given range.
-//! For `T: Into<f64>` it will check if the value is in the given range
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid postal code
+//! of the country read from the given country field, or of the given country code.
+//! For `String` it will check if it's valid postal code of that country.
+//!
+//! Casing, spaces and dashes are ignored, unknown countries are never valid.
 //!
 //! ```rust
 //! #[macro_use]
 //! use validr::*;
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
-//!     field_name_on_self: Option<u8>,
+//!     zip: Option<String>,
+//!     country: Option<String>,
+//!     croatian_zip: Option<String>,
 //! }
 //!
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
-//!         vec![rule_range!(field_name_on_self, Some(10), Some(15))]
+//!         vec![
+//!             rule_postal_code!(zip, country),
+//!             rule_postal_code!(croatian_zip, "HR"),
+//!         ]
 //!     }
 //! }
 //! ```
 //!
-//! ## Custom validation rule
+//! ## Password
 //!
-//! You can always implement a custom validation rule by instead of using provided
-//! macros generate your own `Rule::new()` definition:
+//! For `Option<T: ToString + Clone>` it will check if the field is present and meets the password policy
+//! For `String` it will check if it meets the password policy.
+//!
+//! Each violated requirement is reported as a separate error, e.g. `password_min_length:8`,
+//! `password_uppercase`, `password_digit`, `password_symbol`, `password_repeated:2`,
+//! `password_sequential:3` or `password_score:3`. Optional list of fields can be given that the
+//! password must not match, e.g. `password_matches:username`.
+//!
+//! The bundled list of common passwords can be enabled with the `common-passwords` feature,
+//! which adds `PasswordPolicy::not_common` reporting `password_common`. The list holds only
+//! about 300 of the most common passwords, use a custom rule to check against a longer list.
 //!
 //! ```rust
 //! #[macro_use]
-//! use validr::{Validation, error::ValidationError, Rule};
+//! use validr::*;
+//! use validr::helpers::password::PasswordPolicy;
 //! #[derive(serde::Deserialize, Clone)]
 //! struct Test {
-//!     field_name: String,
+//!     username: Option<String>,
+//!     email: Option<String>,
+//!     password: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         let policy = PasswordPolicy::new()
+//!             .min_length(8)
+//!             .lowercase()
+//!             .uppercase()
+//!             .digit()
+//!             .max_repeated(2)
+//!             .max_sequential(3)
+//!             .min_score(3);
+//!
+//!         vec![rule_password!(password, policy, [username, email])]
+//!     }
+//! }
+//! ```
+//!
+//! ## Country code
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid ISO 3166-1 country code
+//! For `String` it will check if it's valid ISO 3166-1 country code.
+//!
+//! Both alpha-2 (`HR`) and alpha-3 (`HRV`) codes are accepted, pass `alpha2` or `alpha3` to
+//! accept only one of them. Codes are expected in uppercase.
+//! Requires the `country-codes` feature.
+//!
+//! The ISO code lists are only bundled when their feature is enabled, all of them are
+//! disabled by default:
+//!
+//! ```toml
+//! [dependencies]
+//! validr = { version = "0.3.1", features = ["country-codes", "currency-codes", "language-tags", "timezones"] }
+//! ```
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     country: Option<String>,
+//!     country_alpha2: Option<String>,
+//!     country_alpha3: Option<String>,
 //! }
 //!
+//! # #[cfg(feature = "country-codes")]
 //! impl Validation for Test {
 //!     fn rules(&self) -> Vec<Rule<Self>> {
 //!         vec![
-//!             Rule::new("field_name", |obj: &Self, error: &mut ValidationError| {
-//!                 if obj.field_name != "some_validation_rule".to_string() {
-//!                     error.add("my_custom_error_code");
-//!                 }
-//!             }),
+//!             rule_country_code!(country),
+//!             rule_country_code!(country_alpha2, alpha2),
+//!             rule_country_code!(country_alpha3, alpha3),
 //!         ]
 //!     }
 //! }
 //! ```
 //!
-//! # Field modifiers
-//! Before running validation rules you can modify the input data to format it in whatever way you want.
-//! There are some modifiers included, but you can certainly create a custom one to do whatever you want.
+//! ## Currency code
 //!
-//! ## Trim
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid ISO 4217 currency code
+//! For `String` it will check if it's valid ISO 4217 currency code, e.g. `EUR`.
 //!
-//! For `Option<String>` it will check if there is some value and will run the trim on the value.
-//! For `String` it will simply trim it
+//! Requires the `currency-codes` feature.
 //!
 //! ```rust
 //! #[macro_use]
@@ -477,17 +604,21 @@
 //!     field_name_on_self: Option<String>,
 //! }
 //!
+//! # #[cfg(feature = "currency-codes")]
 //! impl Validation for Test {
-//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
-//!         vec![modifier_trim!(field_name_on_self)]
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_currency_code!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Lowercase
+//! ## Language tag
 //!
-//! For `Option<String>` it will check if there is some value and will run the lowercase on the value.
-//! For `String` it will simply lowercase it
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid BCP 47 language tag
+//! For `String` it will check if it's valid BCP 47 language tag, e.g. `hr-HR` or `zh-Hant-TW`.
+//!
+//! Language, script and region subtags are checked against the ISO 639, ISO 15924 and
+//! ISO 3166-1 code lists. Requires the `language-tags` feature.
 //!
 //! ```rust
 //! #[macro_use]
@@ -497,17 +628,20 @@
 //!     field_name_on_self: Option<String>,
 //! }
 //!
+//! # #[cfg(feature = "language-tags")]
 //! impl Validation for Test {
-//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
-//!         vec![modifier_lowercase!(field_name_on_self)]
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_language_tag!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Uppercase
+//! ## Timezone
 //!
-//! For `Option<String>` it will check if there is some value and will run the uppercase on the value.
-//! For `String` it will simply uppercase it
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid IANA time zone name
+//! For `String` it will check if it's valid IANA time zone name, e.g. `Europe/Zagreb`.
+//!
+//! Requires the `timezones` feature.
 //!
 //! ```rust
 //! #[macro_use]
@@ -517,19 +651,39 @@
 //!     field_name_on_self: Option<String>,
 //! }
 //!
+//! # #[cfg(feature = "timezones")]
 //! impl Validation for Test {
-//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
-//!         vec![modifier_uppercase!(field_name_on_self)]
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_timezone!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Capitalize
+//! ## Latitude and longitude
 //!
-//! For `Option<String>` it will check if there is some value and will run the capitalize on the value.
-//! For `String` it will simply capitalize it
+//! For `Option<f64>`, `Option<f32>` or `Option<String>` it will check if the field is present and valid latitude or longitude
+//! For `f64`, `f32` or `String` it will check if it's valid latitude (-90 to 90) or longitude (-180 to 180).
 //!
-//! Capitalize will turn the first char of the string to uppercase, and everything else will be lowercase
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     lat: Option<f64>,
+//!     lng: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_latitude!(lat), rule_longitude!(lng)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Lat lng
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid `"lat,lng"` pair
+//! For `String` it will check if it's valid `"lat,lng"` pair, e.g. `"45.81,15.98"`.
 //!
 //! ```rust
 //! #[macro_use]
@@ -540,16 +694,16 @@
 //! }
 //!
 //! impl Validation for Test {
-//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
-//!         vec![modifier_capitalize!(field_name_on_self)]
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_lat_lng!(field_name_on_self)]
 //!     }
 //! }
 //! ```
 //!
-//! ## Custom modifier
+//! ## GeoJSON point
 //!
-//! Implementing custom modifier is similar to custom validation rule, you will provide a custom
-//! implementation of `Modifier::new()`:
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid GeoJSON point
+//! For `String` it will check if it's valid GeoJSON point, e.g. `{"type": "Point", "coordinates": [15.98, 45.81]}`.
 //!
 //! ```rust
 //! #[macro_use]
@@ -560,44 +714,1464 @@
 //! }
 //!
 //! impl Validation for Test {
-//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_geojson_point!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Bounding box
+//!
+//! It will check if the location is inside the given bounding box. The location can be a single
+//! `"lat,lng"` or GeoJSON point field, or separate latitude and longitude fields, in which case
+//! the error is reported on the latitude field. Bounding boxes crossing the antimeridian are supported.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! use validr::helpers::geo::BoundingBox;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     location: Option<String>,
+//!     lat: Option<f64>,
+//!     lng: Option<f64>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         let croatia = BoundingBox::new(42.39, 13.49, 46.55, 19.45);
+//!
 //!         vec![
-//!             Modifier::new("field_name_on_self", |obj: &mut Self| {
-//!                 obj.field_name_on_self = Some("new_value".to_string());
-//!             }),
+//!             rule_bounding_box!(location, croatia),
+//!             rule_bounding_box!(lat, lng, croatia),
 //!         ]
 //!     }
 //! }
 //! ```
 //!
-mod modifier;
-mod modifiers;
-mod rule;
-mod rules;
-
-pub mod error;
-pub mod helpers;
-pub mod validator;
-pub mod wrappers;
-
-use serde::Deserialize;
-
-pub use crate::validator::Validator;
-pub use modifier::Modifier;
-pub use modifiers::*;
-pub use rule::Rule;
-pub use rules::*;
-
-pub trait Validation: Clone + for<'de> Deserialize<'de> {
-    /// Method that is intended to return vector of all the validation rules
-    fn rules(&self) -> Vec<Rule<Self>> {
-        vec![]
-    }
-
-    /// Method that is intended to return vector of all the modifications to the object
-    /// before the validation runs
-    fn modifiers(&self) -> Vec<Modifier<Self>> {
-        vec![]
+//! ## Base64
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid base64
+//! For `String` it will check if it's valid base64.
+//!
+//! Standard padded alphabet is expected by default, use `url_safe = true` for the URL safe
+//! alphabet, `padded = false` for values without the `=` padding and `max_size` to limit the
+//! number of decoded bytes, reported as `base64_max_size:<bytes>`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     image: Option<String>,
+//!     token: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_base64!(image, max_size = 1024 * 1024),
+//!             rule_base64!(token, url_safe = true, padded = false),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Hex
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid hex encoded value
+//! For `String` it will check if it's valid hex encoded value.
+//!
+//! Optional number of bytes can be given, e.g. 32 for a SHA-256 hash, reported as `hex_bytes:<bytes>`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     color: Option<String>,
+//!     sha256: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_hex!(color), rule_hex!(sha256, 32)]
+//!     }
+//! }
+//! ```
+//!
+//! ## JSON
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid JSON
+//! For `String` it will check if it's valid JSON.
+//!
+//! Optional type can be given, in which case the JSON has to deserialize into it.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Settings {
+//!     theme: String,
+//! }
+//!
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     metadata: Option<String>,
+//!     settings: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_json!(metadata), rule_json!(settings, Settings)]
+//!     }
+//! }
+//! ```
+//!
+//! ## JWT
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and structurally valid JWT
+//! For `String` it will check if it's structurally valid JWT.
+//!
+//! The token has to have three base64url segments, with JSON header and payload. The signature is not verified.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_jwt!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Semver
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid semantic version
+//! For `String` it will check if it's valid semantic version, e.g. `1.2.3` or `1.0.0-beta.1`.
+//!
+//! Optional version requirement can be given, e.g. `>=1.2`, reported as `semver_req:>=1.2`.
+//! The requirement is parsed once when the rule is built, an invalid one panics right there.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     version: Option<String>,
+//!     engine: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_semver!(version), rule_semver!(engine, ">=1.2")]
+//!     }
+//! }
+//! ```
+//!
+//! ## Slug
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid slug
+//! For `String` it will check if it's valid lowercase kebab case slug, e.g. `my-first-post`.
+//!
+//! Optional separator can be given instead of `-`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     slug: Option<String>,
+//!     key: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_slug!(slug), rule_slug!(key, '_')]
+//!     }
+//! }
+//! ```
+//!
+//! ## CSS color
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid CSS color
+//! For `String` it will check if it's valid hex or `rgb()` CSS color, e.g. `#fff`, `#ff000080` or `rgb(255, 0, 0)`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_css_color!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## ISBN
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid ISBN
+//! For `String` it will check if it's valid ISBN-10 or ISBN-13, hyphens and spaces are ignored.
+//!
+//! Use `rule_isbn10!` or `rule_isbn13!` to accept only one of them.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     isbn: Option<String>,
+//!     isbn13: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_isbn!(isbn), rule_isbn13!(isbn13)]
+//!     }
+//! }
+//! ```
+//!
+//! ## ISSN
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid ISSN
+//! For `String` it will check if it's valid ISSN, e.g. `0378-5955`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_issn!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## EAN, UPC and GTIN
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and valid barcode
+//! For `String` it will check if it's valid barcode and its check digit.
+//!
+//! `rule_ean8!`, `rule_ean13!`, `rule_upc_a!` and `rule_gtin14!` accept only their own length,
+//! while `rule_gtin!` accepts any of them.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     ean: Option<String>,
+//!     upc: Option<String>,
+//!     gtin: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_ean13!(ean), rule_upc_a!(upc), rule_gtin!(gtin)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Contains
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and contains given `needle`
+//! For `String` it will check if it contains given `needle`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_contains!(field_name_on_self, "needle".to_string())]
+//!     }
+//! }
+//! ```
+//!
+//! ## Starts with and ends with
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and starts or ends with given `needle`
+//! For `String` it will check if it starts or ends with given `needle`.
+//!
+//! Pass `case_insensitive` as the last argument to ignore the case.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     sku: Option<String>,
+//!     email: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_starts_with!(sku, "SKU-"),
+//!             rule_ends_with!(email, "@company.com", case_insensitive),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Not contains
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and doesn't contain given `needle`
+//! For `String` it will check if it doesn't contain given `needle`.
+//!
+//! Pass `case_insensitive` as the last argument to ignore the case.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_not_contains!(field_name_on_self, "@example.com", case_insensitive)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Contains any and contains all
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and contains any or all of the given needles
+//! For `String` it will check if it contains any or all of the given needles.
+//!
+//! Pass `case_insensitive` as the last argument to ignore the case.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     title: Option<String>,
+//!     description: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_contains_any!(title, vec!["sale", "discount"], case_insensitive),
+//!             rule_contains_all!(description, vec!["size", "color"]),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Equal to
+//!
+//! It validates if two given field names are equal.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//!     field_name_to_compare_to_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_equalt_to!(field_name_on_self, field_name_to_compare_to_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Not equal to
+//!
+//! It validates if two given field names are not equal.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//!     field_name_to_compare_to_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_not_equalt_to!(field_name_on_self, field_name_to_compare_to_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## In
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and will match its value to haystack of values
+//! For `String` it will check if its in the haystack value
+//!
+//! Pass `case_insensitive` as the last argument to ignore the case. Instead of the haystack,
+//! `variants = Type` can be given for a type implementing `strum::VariantNames`, so the allowed
+//! values always match the enum.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, strum::VariantNames)]
+//! #[strum(serialize_all = "snake_case")]
+//! enum Status {
+//!     Draft,
+//!     Published,
+//! }
+//!
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//!     status: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_in!(field_name_on_self, vec![
+//!                 "allowed_value".to_string(),
+//!                 "another_allowed_value".to_string()
+//!             ], case_insensitive),
+//!             rule_in!(status, variants = Status),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Not in
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and its value is not in the haystack of values
+//! For `String` it will check if its not in the haystack value
+//!
+//! Supports the same `case_insensitive` and `variants = Type` options as `rule_in!`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     username: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_not_in!(username, vec!["admin", "root"], case_insensitive)]
+//!     }
+//! }
+//! ```
+//!
+//! ## length min
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and has `min` number of chars
+//! For `String` it will check if it has `min` number of chars
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_length_min!(field_name_on_self, 2)]
+//!     }
+//! }
+//! ```
+//!
+//! ## length max
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and has `max` number of chars
+//! For `String` it will check if it has `max` number of chars
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_length_max!(field_name_on_self, 15)]
+//!     }
+//! }
+//! ```
+//!
+//! ## length equal
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and has `eq` number of chars
+//! For `String` it will check if it has `eq` number of chars
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_length_eq!(field_name_on_self, 10)]
+//!     }
+//! }
+//! ```
+//!
+//! ## length not equal
+//!
+//! For `Option<T: ToString + Clone>` it will check if the field is present and has `ne` number of chars
+//! For `String` it will check if it has `ne` number of chars
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_length_ne!(field_name_on_self, 11)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Range
+//!
+//! For `Option<T: Into<f64> + PartialOrd + Clone>` it will check that the value is present and within given range.
+//! For `T: Into<f64>` it will check if the value is in the given range.
+//! `String` and `Option<String>` fields are parsed first, if that fails `numeric` is reported
+//! instead of `range`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<u8>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_range!(field_name_on_self, Some(10), Some(15))]
+//!     }
+//! }
+//! ```
+//!
+//! ## Numeric strings
+//!
+//! For clients that send numbers as strings. `rule_numeric!` accepts anything that parses as a
+//! finite number, e.g. `-0.5` or `1e3`, `rule_integer_string!` only whole numbers with an
+//! optional sign and `rule_decimal_string!` whole or decimal numbers without an exponent.
+//! Whitespace is not allowed, trim the value first.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     quantity: String,
+//!     price: Option<String>,
+//!     score: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_integer_string!(quantity),
+//!             rule_range!(quantity, Some(1), Some(100)),
+//!             rule_decimal_string!(price),
+//!             rule_numeric!(score),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Positive, non negative and negative
+//!
+//! Work with the same number types as `rule_range!`. `rule_positive!` requires a value greater
+//! than zero, `rule_non_negative!` zero or greater and `rule_negative!` less than zero.
+//! `NaN` never passes. For `Option` the value is only checked when present.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     quantity: u32,
+//!     balance: Option<f64>,
+//!     offset: i32,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_positive!(quantity),
+//!             rule_non_negative!(balance),
+//!             rule_negative!(offset),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Multiple of
+//!
+//! Checks that the number is a multiple of the given step, e.g. quantities in packs of 6.
+//! Floating point rounding errors are tolerated. Reports `multiple_of:<step>`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     price: Option<f64>,
+//!     pack_size: u32,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_multiple_of!(price, 0.05),
+//!             rule_multiple_of!(pack_size, 6),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Decimal places
+//!
+//! Checks that the number has at most the given number of decimal places, reports
+//! `decimal_places:<max>`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     amount: Option<f64>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_decimal_places!(amount, 2)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Finite and integer
+//!
+//! `rule_finite!` rejects `NaN` and infinity, `rule_integer!` rejects numbers with a fraction
+//! as well as non finite ones. Integer types always pass.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     ratio: f64,
+//!     count: Option<f32>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_finite!(ratio), rule_integer!(count)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Confusable
+//!
+//! Rejects usernames that mix scripts in a way that is used to impersonate others, e.g.
+//! `pаypal` with the Cyrillic `а`, reported as `confusable`. Digits, punctuation and scripts
+//! that are written together, like Han with Hiragana, are allowed.
+//!
+//! With the list of reserved names it also reports `confusable:<names>` when the value looks
+//! the same as one of them after normalizing the case, full-width characters and homoglyphs,
+//! e.g. `ＡＤＭＩＮ` or `adrnin` for `admin`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     username: String,
+//!     display_name: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             rule_confusable!(username, vec!["admin", "support"]),
+//!             rule_confusable!(display_name),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## No HTML
+//!
+//! Rejects plain text fields that contain tags or comments, reported as `no_html`. A lone `<`
+//! like in `1 < 2` or `<3` is allowed.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     name: String,
+//!     bio: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_no_html!(name), rule_no_html!(bio)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Custom validation rule
+//!
+//! You can always implement a custom validation rule by instead of using provided
+//! macros generate your own `Rule::new()` definition:
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::{Validation, error::ValidationError, Rule};
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name: String,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![
+//!             Rule::new("field_name", |obj: &Self, error: &mut ValidationError| {
+//!                 if obj.field_name != "some_validation_rule".to_string() {
+//!                     error.add("my_custom_error_code");
+//!                 }
+//!             }),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! # Field modifiers
+//! Before running validation rules you can modify the input data to format it in whatever way you want.
+//! There are some modifiers included, but you can certainly create a custom one to do whatever you want.
+//!
+//! ## Trim
+//!
+//! For `Option<String>` it will check if there is some value and will run the trim on the value.
+//! For `String` it will simply trim it
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_trim!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Trim start and trim end
+//!
+//! Same as `modifier_trim!` but only removes the leading or the trailing whitespace.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     prefix: Option<String>,
+//!     suffix: String,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_trim_start!(prefix), modifier_trim_end!(suffix)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Text cleanup
+//!
+//! Modifiers for user generated content, for `String` and `Option<String>`:
+//!
+//! - `modifier_collapse_whitespace!` replaces every run of spaces, tabs and newlines with a single space
+//! - `modifier_strip_control_characters!` removes control characters, tabs and newlines are kept
+//! - `modifier_remove_zero_width!` removes zero width spaces, word joiners and byte order marks,
+//!   zero width joiners and non-joiners are kept for emoji sequences and scripts that need them
+//! - `modifier_normalize_newlines!` converts `\r\n` and `\r` to `\n`
+//!
+//! Modifiers run in the given order, so trim after collapsing the whitespace.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     title: String,
+//!     body: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_remove_zero_width!(title),
+//!             modifier_collapse_whitespace!(title),
+//!             modifier_trim!(title),
+//!             modifier_strip_control_characters!(body),
+//!             modifier_normalize_newlines!(body),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Lowercase
+//!
+//! For `Option<String>` it will check if there is some value and will run the lowercase on the value.
+//! For `String` it will simply lowercase it
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_lowercase!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Uppercase
+//!
+//! For `Option<String>` it will check if there is some value and will run the uppercase on the value.
+//! For `String` it will simply uppercase it
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_uppercase!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Capitalize
+//!
+//! For `Option<String>` it will check if there is some value and will run the capitalize on the value.
+//! For `String` it will simply capitalize it
+//!
+//! Capitalize will turn the first char of the string to uppercase, and everything else will be lowercase
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_capitalize!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Title case
+//!
+//! Uppercases the first letter of every word and lowercases the rest, e.g. `the QUICK brown-fox`
+//! becomes `The Quick Brown-Fox`. Whitespace and punctuation are kept as they are.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     title: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_title_case!(title)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Slugify
+//!
+//! Turns the value into a slug accepted by `rule_slug!`, transliterating it to ASCII first,
+//! e.g. `Čaj & kava: 10 savjeta` becomes `caj-kava-10-savjeta`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     slug: String,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_slugify!(slug)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Snake case, camel case and kebab case
+//!
+//! Convert identifiers between the naming conventions, words are split on separators and
+//! case changes, e.g. `XMLHttpRequest` becomes `xml_http_request`, `xmlHttpRequest` and
+//! `xml-http-request`.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     column: String,
+//!     property: Option<String>,
+//!     css_class: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_snake_case!(column),
+//!             modifier_camel_case!(property),
+//!             modifier_kebab_case!(css_class),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Normalize MAC
+//!
+//! For `Option<String>` it will check if there is some value and will normalize the MAC address.
+//! For `String` it will simply normalize the MAC address
+//!
+//! Any of the formats accepted by `rule_mac!` is turned into the canonical lowercase colon form,
+//! e.g. `001A.2B3C.4D5E` becomes `00:1a:2b:3c:4d:5e`. Invalid values are left untouched.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_normalize_mac!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Phone
+//!
+//! For `Option<String>` it will check if there is some value and will format the phone number.
+//! For `String` it will simply format the phone number
+//!
+//! `modifier_phone_e164!` formats the number as E.164 (`+385912345678`), while
+//! `modifier_phone_national!` and `modifier_phone_international!` use the national
+//! (`091 234 5678`) and international (`+385 91 234 5678`) formats. The optional second
+//! argument is the region used for numbers given without the `+` prefix, it is resolved once
+//! when the modifier is built and an unknown country code panics right there. Values that
+//! can't be parsed are left untouched so `rule_phone!` can still report them.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_phone_e164!(field_name_on_self, "HR")]
+//!     }
+//! }
+//! ```
+//!
+//! ## Normalize credit card
+//!
+//! For `Option<String>` it will check if there is some value and will remove spaces and dashes from it.
+//! For `String` it will simply remove spaces and dashes
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_normalize_credit_card!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Normalize IBAN
+//!
+//! For `Option<String>` it will check if there is some value and will remove spaces and uppercase it.
+//! For `String` it will simply remove spaces and uppercase it
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_normalize_iban!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Normalize postal code
+//!
+//! For `Option<String>` it will check if there is some value and will format it in the canonical
+//! form of the country read from the given country field, or of the given country code.
+//! For `String` it will simply format it, e.g. UK `sw1a1aa` becomes `SW1A 1AA`.
+//!
+//! Values that are not valid postal codes are left unchanged.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     zip: Option<String>,
+//!     country: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_normalize_postal_code!(zip, country)]
+//!     }
+//! }
+//! ```
+//!
+//! ## ISBN-13
+//!
+//! For `Option<String>` it will check if there is some value and will convert ISBN-10 to ISBN-13.
+//! For `String` it will simply convert it, e.g. `0-306-40615-2` becomes `9780306406157`.
+//!
+//! Hyphens are removed from valid ISBN-13, values that are not valid ISBNs are left unchanged.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_isbn13!(field_name_on_self)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Coerce
+//!
+//! Parses a `String` or `Option<String>` field into a sibling typed field, e.g. form encoded
+//! data where every value arrives as a string. `modifier_coerce!` works with any target that
+//! implements `FromStr`, like numbers, `IpAddr` or a date type, wrapped in `Option` or one of
+//! the primitive number types. `modifier_coerce_bool!` understands `true`/`false`, `yes`/`no`,
+//! `y`/`n`, `on`/`off` and `1`/`0`.
+//!
+//! Surrounding whitespace is ignored, a missing or blank value clears an `Option` target and
+//! leaves any other target as it is. If the value can not be parsed the target is cleared
+//! and `coerce` or `coerce_bool` is reported for the target field.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     quantity_raw: Option<String>,
+//!     #[serde(skip)]
+//!     quantity: Option<u32>,
+//!     newsletter_raw: String,
+//!     #[serde(default)]
+//!     newsletter: bool,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_coerce!(quantity_raw, quantity),
+//!             modifier_coerce_bool!(newsletter_raw, newsletter),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Normalize
+//!
+//! Normalizes the value to one of the Unicode normalization forms `NFC`, `NFD`, `NFKC` or
+//! `NFKD`, so that e.g. the composed `é` and `e` followed by a combining accent are stored the
+//! same. `NFKC` also turns full-width and other compatibility characters into their plain
+//! form, which is what you want for identifiers like usernames.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     username: String,
+//!     bio: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_normalize!(username, NFKC), modifier_normalize!(bio, NFC)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Case fold
+//!
+//! Folds the case for caseless comparison and uniqueness checks. Unlike `modifier_lowercase!`
+//! it also maps e.g. `ß` to `ss` and `ς` to `σ`, so `Straße` and `STRASSE` end up the same.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     username: String,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_normalize!(username, NFKC),
+//!             modifier_case_fold!(username),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Sanitize HTML
+//!
+//! Removes every tag and attribute that the `HtmlPolicy` doesn't allow, using
+//! [ammonia](https://docs.rs/ammonia). Content of `script`, `style` and similar elements is
+//! removed as well. Allowed tags are written out again, so
+//! unclosed tags are closed and attribute values escaped. URL attributes like `href` may only
+//! use the allowed schemes, `http`, `https` and `mailto` by default, or be relative.
+//!
+//! Without a policy `HtmlPolicy::basic()` is used, which allows paragraphs, line breaks,
+//! emphasis, lists, quotes, code and links.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! use validr::helpers::html::HtmlPolicy;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     comment: Option<String>,
+//!     article: String,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_sanitize_html!(comment),
+//!             modifier_sanitize_html!(
+//!                 article,
+//!                 HtmlPolicy::basic()
+//!                     .tags(&["h2", "h3"])
+//!                     .attributes("img", &["src", "alt"])
+//!                     .url_schemes(&["https"])
+//!             ),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Strip tags and escape HTML
+//!
+//! For plain text fields, `modifier_strip_tags!` removes all the tags and comments and keeps
+//! the text, entities like `&amp;` are decoded. `modifier_escape_html!` escapes `&`, `<`, `>`,
+//! `"` and `'` instead.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     title: String,
+//!     signature: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![modifier_strip_tags!(title), modifier_escape_html!(signature)]
+//!     }
+//! }
+//! ```
+//!
+//! ## Empty to none
+//!
+//! Turns `Some("")` into `None`, for `Option<String>` and `Option<Vec<T>>`. Trim the value first
+//! to treat whitespace only values as empty.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     nickname: Option<String>,
+//!     tags: Option<Vec<String>>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_trim!(nickname),
+//!             modifier_empty_to_none!(nickname),
+//!             modifier_empty_to_none!(tags),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Default
+//!
+//! Fills in `None` for any `Option<T>` field, `&str` values are accepted for `String` fields.
+//! `modifier_default_with!` calls the function only when the default is needed, e.g. for
+//! timestamps.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     status: Option<String>,
+//!     page_size: Option<u32>,
+//!     created_at: Option<u64>,
+//! }
+//!
+//! fn now() -> u64 {
+//!     std::time::SystemTime::now()
+//!         .duration_since(std::time::UNIX_EPOCH)
+//!         .unwrap()
+//!         .as_secs()
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_default!(status, "draft"),
+//!             modifier_default!(page_size, 20),
+//!             modifier_default_with!(created_at, now),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Truncate
+//!
+//! Keeps at most the given number of characters, for `String` and `Option<String>`. The unit is
+//! `chars` (default) or `graphemes`, which also keeps letters with combining accents, flags and
+//! emoji sequences whole. A UTF-8 sequence is never split.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     user_agent: String,
+//!     message: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_truncate!(user_agent, 255),
+//!             modifier_truncate!(message, 140, graphemes),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Clamp and round
+//!
+//! Work with the same number types as `rule_range!`. `modifier_clamp!` moves the value into the
+//! given range instead of rejecting it, integer fields are compared exactly, even above 2^53.
+//! `modifier_round!` rounds floats to the given number of decimal places, halfway cases away
+//! from zero. For `Option` only present values are changed.
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     page_size: u32,
+//!     volume: Option<f64>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             modifier_clamp!(page_size, 1, 100),
+//!             modifier_clamp!(volume, 0, 1),
+//!             modifier_round!(volume, 2),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! ## Custom modifier
+//!
+//! Implementing custom modifier is similar to custom validation rule, you will provide a custom
+//! implementation of `Modifier::new()`:
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     field_name_on_self: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             Modifier::new("field_name_on_self", |obj: &mut Self| {
+//!                 obj.field_name_on_self = Some("new_value".to_string());
+//!             }),
+//!         ]
+//!     }
+//! }
+//! ```
+//!
+//! Modifier that can fail, e.g. because it parses or decodes the value, is created with
+//! `Modifier::try_new()` and reports error codes the same way as the rules. Rules still run
+//! for the field, unless `skip_rules_on_modifier_error` returns `true`:
+//!
+//! ```rust
+//! #[macro_use]
+//! use validr::*;
+//! use validr::error::ValidationError;
+//! #[derive(serde::Deserialize, Clone)]
+//! struct Test {
+//!     payload: Option<String>,
+//! }
+//!
+//! impl Validation for Test {
+//!     fn rules(&self) -> Vec<Rule<Self>> {
+//!         vec![rule_json!(payload)]
+//!     }
+//!
+//!     fn modifiers(&self) -> Vec<Modifier<Self>> {
+//!         vec![
+//!             Modifier::try_new("payload", |obj: &mut Self, error: &mut ValidationError| {
+//!                 if let Some(v) = &obj.payload {
+//!                     match helpers::base64::decode_base64(v, &Default::default()) {
+//!                         Some(bytes) => obj.payload = String::from_utf8(bytes).ok(),
+//!                         None => error.add("base64"),
+//!                     }
+//!                 }
+//!             }),
+//!         ]
+//!     }
+//!
+//!     fn skip_rules_on_modifier_error(&self) -> bool {
+//!         true
+//!     }
+//! }
+//! ```
+//!
+// `% n == 0` and `map_or(true, ..)` keep the crate building on compilers without
+// `is_multiple_of` and `is_none_or`
+#![allow(clippy::manual_is_multiple_of, clippy::unnecessary_map_or)]
+
+mod modifier;
+mod modifiers;
+mod rule;
+mod rules;
+
+pub mod error;
+pub mod helpers;
+pub mod validator;
+pub mod wrappers;
+
+use serde::Deserialize;
+
+pub use crate::validator::Validator;
+pub use modifier::Modifier;
+pub use rule::Rule;
+
+#[doc(hidden)]
+pub use strum;
+
+pub trait Validation: Clone + for<'de> Deserialize<'de> {
+    /// Method that is intended to return vector of all the validation rules
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![]
+    }
+
+    /// Method that is intended to return vector of all the modifications to the object
+    /// before the validation runs
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![]
+    }
+
+    /// Method that is intended to return whether the rules should be skipped for
+    /// fields whose modifier reported an error
+    fn skip_rules_on_modifier_error(&self) -> bool {
+        false
     }
 
     /// This will run the validation and return the object if all the validations pass.
@@ -606,8 +2180,9 @@ pub trait Validation: Clone + for<'de> Deserialize<'de> {
     fn validate(self) -> Result<Self, error::ValidationErrors> {
         let rules = self.rules();
         let modifiers = self.modifiers();
+        let skip = self.skip_rules_on_modifier_error();
 
-        let mut validator = Validator::new(self);
+        let mut validator = Validator::new(self).skip_rules_on_modifier_error(skip);
 
         for rule in rules {
             validator = validator.add_validation(rule);
@@ -1,8 +1,11 @@
+use super::error::ValidationError;
 use serde::Deserialize;
 
+type ModifierRunner<T> = Box<dyn Fn(&mut T, &mut ValidationError) + 'static>;
+
 pub struct Modifier<T> {
     pub field: String,
-    runner: Box<dyn Fn(&mut T) + 'static>,
+    runner: ModifierRunner<T>,
 }
 
 impl<T> Modifier<T>
@@ -13,6 +16,18 @@ where
     pub fn new<F>(field_name: &str, runner: F) -> Self
     where
         F: Fn(&mut T) + 'static,
+    {
+        Modifier::<T> {
+            field: field_name.to_string(),
+            runner: Box::new(move |item: &mut T, _error: &mut ValidationError| runner(item)),
+        }
+    }
+
+    /// Construct the new custom modifier that can report errors for the field,
+    /// e.g. when the value can not be parsed
+    pub fn try_new<F>(field_name: &str, runner: F) -> Self
+    where
+        F: Fn(&mut T, &mut ValidationError) + 'static,
     {
         Modifier::<T> {
             field: field_name.to_string(),
@@ -22,6 +37,15 @@ where
 
     /// Handle the modification
     pub fn handle(&self, item: &mut T) {
-        (self.runner)(item);
+        let mut error = ValidationError::new();
+
+        self.try_handle(item, &mut error);
+    }
+
+    /// Handle the modification and collect the errors it reports
+    pub fn try_handle(&self, item: &mut T, error: &mut ValidationError) {
+        error.set_field_name(&self.field);
+
+        (self.runner)(item, error);
     }
 }
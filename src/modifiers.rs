@@ -45,3 +45,485 @@ macro_rules! modifier_capitalize {
         })
     };
 }
+
+#[macro_export]
+macro_rules! modifier_normalize_mac {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_mac()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_phone_e164 {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(
+                    &$crate::helpers::phone::PhoneOptions::new(),
+                    $crate::helpers::phone::PhoneFormat::E164,
+                )
+        })
+    };
+
+    ($name:ident, $region:expr) => {{
+        let options = $crate::helpers::phone::PhoneOptions::new().region($region);
+
+        $crate::Modifier::new(stringify!($name), move |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(&options, $crate::helpers::phone::PhoneFormat::E164)
+        })
+    }};
+}
+
+#[macro_export]
+macro_rules! modifier_phone_national {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(
+                    &$crate::helpers::phone::PhoneOptions::new(),
+                    $crate::helpers::phone::PhoneFormat::National,
+                )
+        })
+    };
+
+    ($name:ident, $region:expr) => {{
+        let options = $crate::helpers::phone::PhoneOptions::new().region($region);
+
+        $crate::Modifier::new(stringify!($name), move |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(&options, $crate::helpers::phone::PhoneFormat::National)
+        })
+    }};
+}
+
+#[macro_export]
+macro_rules! modifier_phone_international {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(
+                    &$crate::helpers::phone::PhoneOptions::new(),
+                    $crate::helpers::phone::PhoneFormat::International,
+                )
+        })
+    };
+
+    ($name:ident, $region:expr) => {{
+        let options = $crate::helpers::phone::PhoneOptions::new().region($region);
+
+        $crate::Modifier::new(stringify!($name), move |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_phone(&options, $crate::helpers::phone::PhoneFormat::International)
+        })
+    }};
+}
+
+#[macro_export]
+macro_rules! modifier_normalize_credit_card {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_credit_card()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_normalize_iban {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_iban()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_normalize_postal_code {
+    ($name:ident, $country:literal) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_postal_code($country)
+        })
+    };
+
+    ($name:ident, $country_name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            use $crate::wrappers::rules::SomeOrStringWrapper as _;
+            let country = $crate::wrappers::rules::SomeOrString(&obj.$country_name)
+                .0
+                .value()
+                .unwrap_or_default();
+
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_postal_code(&country)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_isbn13 {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_isbn13()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_coerce {
+    ($from:ident, $to:ident) => {
+        $crate::Modifier::try_new(
+            stringify!($to),
+            |obj: &mut Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::modifiers::SomeOrValueWrapper;
+                use $crate::wrappers::rules::SomeOrStringWrapper as _;
+                let raw = $crate::wrappers::rules::SomeOrString(&obj.$from).0.value();
+
+                if !obj.$to.m_coerce(raw) {
+                    error.add("coerce");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_coerce_bool {
+    ($from:ident, $to:ident) => {
+        $crate::Modifier::try_new(
+            stringify!($to),
+            |obj: &mut Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::modifiers::SomeOrValueWrapper;
+                use $crate::wrappers::rules::SomeOrStringWrapper as _;
+                let raw = $crate::wrappers::rules::SomeOrString(&obj.$from).0.value();
+
+                if !obj.$to.m_coerce_bool(raw) {
+                    error.add("coerce_bool");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_collapse_whitespace {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_collapse_whitespace()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_trim_start {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_trim_start()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_trim_end {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_trim_end()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_strip_control_characters {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_strip_control_characters()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_remove_zero_width {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_remove_zero_width()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_normalize_newlines {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize_newlines()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_normalize {
+    ($name:ident, NFC) => {
+        $crate::modifier_normalize!(@modifier $name, Nfc)
+    };
+
+    ($name:ident, NFD) => {
+        $crate::modifier_normalize!(@modifier $name, Nfd)
+    };
+
+    ($name:ident, NFKC) => {
+        $crate::modifier_normalize!(@modifier $name, Nfkc)
+    };
+
+    ($name:ident, NFKD) => {
+        $crate::modifier_normalize!(@modifier $name, Nfkd)
+    };
+
+    (@modifier $name:ident, $form:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_normalize($crate::helpers::unicode::NormalizationForm::$form)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_case_fold {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_case_fold()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_title_case {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_title_case()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_slugify {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_slugify()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_snake_case {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_snake_case()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_camel_case {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_camel_case()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_kebab_case {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_kebab_case()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_sanitize_html {
+    ($name:ident) => {
+        $crate::modifier_sanitize_html!($name, $crate::helpers::html::HtmlPolicy::basic())
+    };
+
+    ($name:ident, $policy:expr) => {{
+        let policy: $crate::helpers::html::HtmlPolicy = $policy;
+
+        $crate::Modifier::new(stringify!($name), move |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_sanitize_html(&policy)
+        })
+    }};
+}
+
+#[macro_export]
+macro_rules! modifier_strip_tags {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_strip_tags()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_escape_html {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_escape_html()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_empty_to_none {
+    ($name:ident) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrEmptyWrapper;
+            obj.$name = obj.$name.clone().m_empty_to_none()
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_default {
+    ($name:ident, $value:expr) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrDefaultWrapper;
+            obj.$name = obj.$name.clone().m_default($value)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_default_with {
+    ($name:ident, $default:expr) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrDefaultWrapper;
+            obj.$name = obj.$name.clone().m_default_with($default)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_truncate {
+    ($name:ident, $max:expr) => {
+        $crate::modifier_truncate!($name, $max, chars)
+    };
+
+    ($name:ident, $max:expr, chars) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_truncate_chars($max)
+        })
+    };
+
+    ($name:ident, $max:expr, graphemes) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrStringWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrString(obj.$name.clone())
+                .0
+                .m_truncate_graphemes($max)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_clamp {
+    ($name:ident, $min:expr, $max:expr) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrNumberWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrNumber(obj.$name.clone())
+                .0
+                .m_clamp($min, $max)
+        })
+    };
+}
+
+#[macro_export]
+macro_rules! modifier_round {
+    ($name:ident, $decimals:expr) => {
+        $crate::Modifier::new(stringify!($name), |obj: &mut Self| {
+            use $crate::wrappers::modifiers::SomeOrNumberWrapper;
+            obj.$name = $crate::wrappers::modifiers::SomeOrNumber(obj.$name.clone())
+                .0
+                .m_round($decimals)
+        })
+    };
+}
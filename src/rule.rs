@@ -1,9 +1,11 @@
 use super::error::ValidationError;
 use serde::Deserialize;
 
+type RuleRunner<T> = Box<dyn Fn(&T, &mut ValidationError) + 'static>;
+
 pub struct Rule<T> {
     pub field: String,
-    runner: Box<dyn Fn(&T, &mut ValidationError) + 'static>,
+    runner: RuleRunner<T>,
 }
 
 impl<T> Rule<T>
@@ -66,13 +66,1101 @@ macro_rules! rule_url {
 
 #[macro_export]
 macro_rules! rule_phone {
+    (@option region $region:expr) => {
+        $region
+    };
+
+    (@option countries [$($country:expr),* $(,)?]) => {
+        vec![$($country),*]
+    };
+
+    (@option types [$($type:ident),* $(,)?]) => {
+        vec![$($crate::helpers::phone::PhoneType::$type),*]
+    };
+
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.phone() {
+                    error.add("phone");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $($option:ident = $value:tt),+ $(,)?) => {{
+        let options = $crate::helpers::phone::PhoneOptions::new()
+            $(.$option($crate::rule_phone!(@option $option $value)))+;
+
+        $crate::Rule::new(
+            stringify!($name),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if let Err(e) = $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .phone_with(&options)
+                {
+                    error.add(&e.code());
+                }
+            },
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! rule_non_control_character {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .non_control_character()
+                {
+                    error.add("non_control_character");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_ip {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip() {
+                    error.add("ip");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_ip_v4 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip_v4() {
+                    error.add("ip_v4");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_ip_v6 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip_v6() {
+                    error.add("ip_v6");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_mac {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.mac() {
+                    error.add("mac");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_mac_eui48 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .mac_eui48()
+                {
+                    error.add("mac_eui48");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_mac_eui64 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .mac_eui64()
+                {
+                    error.add("mac_eui64");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_port {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.port() {
+                    error.add("port");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_socket_address {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .socket_address()
+                {
+                    error.add("socket_address");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_credit_card {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .credit_card()
+                {
+                    error.add("credit_card");
+                }
+            },
+        )
+    };
+
+    ($name:ident, brands = [$($brand:ident),* $(,)?]) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if let Err(e) = $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .credit_card_brand(&[$($crate::helpers::card::CardType::$brand),*])
+                {
+                    error.add(&e.code());
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_card_expiry {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .card_expiry()
+                {
+                    error.add("card_expiry");
+                }
+            },
+        )
+    };
+
+    ($month:ident, $year:ident) => {
+        $crate::Rule::new(
+            stringify!($month),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                let month = $crate::wrappers::rules::SomeOrString(&obj.$month).0.value();
+                let year = $crate::wrappers::rules::SomeOrString(&obj.$year).0.value();
+
+                if let (Some(month), Some(year)) = (month, year) {
+                    if !$crate::helpers::card::validate_card_expiry(month, year) {
+                        error.add("card_expiry");
+                    }
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_cvv {
+    ($name:ident, $card_name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if let Some(cvv) = $crate::wrappers::rules::SomeOrString(&obj.$name).0.value() {
+                    let card_type = $crate::wrappers::rules::SomeOrString(&obj.$card_name)
+                        .0
+                        .value()
+                        .and_then(|card| {
+                            $crate::helpers::card::credit_card_type(
+                                $crate::helpers::card::normalize_credit_card(card),
+                            )
+                        });
+
+                    if !$crate::helpers::card::validate_cvv(cvv, card_type) {
+                        error.add("cvv");
+                    }
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_iban {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.iban() {
+                    error.add("iban");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_bic {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.bic() {
+                    error.add("bic");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_vat {
+    ($name:ident, $country:literal) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .vat($country)
+                {
+                    error.add("vat");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $country_name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                let country = $crate::wrappers::rules::SomeOrString(&obj.$country_name)
+                    .0
+                    .value()
+                    .unwrap_or_default();
+
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .vat(&country)
+                {
+                    error.add("vat");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_postal_code {
+    ($name:ident, $country:literal) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .postal_code($country)
+                {
+                    error.add("postal_code");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $country_name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                let country = $crate::wrappers::rules::SomeOrString(&obj.$country_name)
+                    .0
+                    .value()
+                    .unwrap_or_default();
+
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .postal_code(&country)
+                {
+                    error.add("postal_code");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_password {
+    ($name:ident, $policy:expr) => {
+        $crate::rule_password!($name, $policy, [])
+    };
+
+    ($name:ident, $policy:expr, [$($other:ident),* $(,)?]) => {{
+        let policy: $crate::helpers::password::PasswordPolicy = $policy;
+
+        $crate::Rule::new(
+            stringify!($name),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if let Err(errors) = $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .password(&policy)
+                {
+                    for e in errors {
+                        error.add(&e.code());
+                    }
+                }
+
+                $(
+                    let password = $crate::wrappers::rules::SomeOrString(&obj.$name)
+                        .0
+                        .value();
+                    let other = $crate::wrappers::rules::SomeOrString(&obj.$other)
+                        .0
+                        .value();
+
+                    if let (Some(password), Some(other)) = (password, other) {
+                        if $crate::helpers::password::password_matches(password, other) {
+                            error.add(concat!("password_matches:", stringify!($other)));
+                        }
+                    }
+                )*
+            },
+        )
+    }};
+}
+
+#[cfg(feature = "country-codes")]
+#[macro_export]
+macro_rules! rule_country_code {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .country_code()
+                {
+                    error.add("country_code");
+                }
+            },
+        )
+    };
+
+    ($name:ident, alpha2) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .country_code_alpha2()
+                {
+                    error.add("country_code_alpha2");
+                }
+            },
+        )
+    };
+
+    ($name:ident, alpha3) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .country_code_alpha3()
+                {
+                    error.add("country_code_alpha3");
+                }
+            },
+        )
+    };
+}
+
+#[cfg(feature = "currency-codes")]
+#[macro_export]
+macro_rules! rule_currency_code {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .currency_code()
+                {
+                    error.add("currency_code");
+                }
+            },
+        )
+    };
+}
+
+#[cfg(feature = "language-tags")]
+#[macro_export]
+macro_rules! rule_language_tag {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .language_tag()
+                {
+                    error.add("language_tag");
+                }
+            },
+        )
+    };
+}
+
+#[cfg(feature = "timezones")]
+#[macro_export]
+macro_rules! rule_timezone {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .timezone()
+                {
+                    error.add("timezone");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_lat_lng {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .lat_lng()
+                {
+                    error.add("lat_lng");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_geojson_point {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .geojson_point()
+                {
+                    error.add("geojson_point");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_latitude {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrCoordinateWrapper;
+                if $crate::wrappers::rules::SomeOrCoordinate(&obj.$name)
+                    .0
+                    .latitude()
+                {
+                    error.add("latitude");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_longitude {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrCoordinateWrapper;
+                if $crate::wrappers::rules::SomeOrCoordinate(&obj.$name)
+                    .0
+                    .longitude()
+                {
+                    error.add("longitude");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_bounding_box {
+    ($lat:ident, $lng:ident, $bbox:expr) => {{
+        let bbox: $crate::helpers::geo::BoundingBox = $bbox;
+
+        $crate::Rule::new(
+            stringify!($lat),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrCoordinateWrapper;
+                let lat = $crate::wrappers::rules::SomeOrCoordinate(&obj.$lat)
+                    .0
+                    .coordinate();
+                let lng = $crate::wrappers::rules::SomeOrCoordinate(&obj.$lng)
+                    .0
+                    .coordinate();
+
+                match (lat, lng) {
+                    (None, None) => (),
+                    (Some(Some(lat)), Some(Some(lng))) if bbox.contains(lat, lng) => (),
+                    _ => error.add("bounding_box"),
+                }
+            },
+        )
+    }};
+
+    ($name:ident, $bbox:expr) => {{
+        let bbox: $crate::helpers::geo::BoundingBox = $bbox;
+
+        $crate::Rule::new(
+            stringify!($name),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .bounding_box(&bbox)
+                {
+                    error.add("bounding_box");
+                }
+            },
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! rule_base64 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.base64() {
+                    error.add("base64");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $($option:ident = $value:expr),+ $(,)?) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                let options = $crate::helpers::base64::Base64Options::new()
+                    $(.$option($value))+;
+
+                if let Err(e) = $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .base64_with(&options)
+                {
+                    error.add(&e.code());
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_hex {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.hex() {
+                    error.add("hex");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $bytes:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.hex() {
+                    error.add("hex");
+                } else if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .hex_bytes($bytes)
+                {
+                    error.add(&format!("hex_bytes:{}", $bytes));
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_json {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.json() {
+                    error.add("json");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $type:ty) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .json_as::<$type>()
+                {
+                    error.add("json");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_jwt {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.jwt() {
+                    error.add("jwt");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_semver {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.semver() {
+                    error.add("semver");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $req:expr) => {{
+        let req = $req;
+        let code = format!("semver_req:{}", req);
+        let req = $crate::helpers::semver::parse_semver_req(&req);
+
+        $crate::Rule::new(
+            stringify!($name),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.semver() {
+                    error.add("semver");
+                } else if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .semver_req(&req)
+                {
+                    error.add(&code);
+                }
+            },
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! rule_slug {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.slug() {
+                    error.add("slug");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $separator:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .slug_with($separator)
+                {
+                    error.add("slug");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_css_color {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .css_color()
+                {
+                    error.add("css_color");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_isbn {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.isbn() {
+                    error.add("isbn");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_isbn10 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.isbn10() {
+                    error.add("isbn10");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_isbn13 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.isbn13() {
+                    error.add("isbn13");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_issn {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.issn() {
+                    error.add("issn");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_ean8 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ean8() {
+                    error.add("ean8");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_ean13 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ean13() {
+                    error.add("ean13");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_upc_a {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.upc_a() {
+                    error.add("upc_a");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_gtin14 {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.gtin14() {
+                    error.add("gtin14");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_gtin {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.gtin() {
+                    error.add("gtin");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_numeric {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .numeric()
+                {
+                    error.add("numeric");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_integer_string {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .integer_string()
+                {
+                    error.add("integer_string");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_decimal_string {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .decimal_string()
+                {
+                    error.add("decimal_string");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_confusable {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .confusable()
+                {
+                    error.add("confusable");
+                }
+            },
+        )
+    };
+
+    ($name:ident, $reserved:expr) => {{
+        let reserved = $reserved;
+        let code = format!(
+            "confusable:{}",
+            reserved
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+
+        $crate::Rule::new(
+            stringify!($name),
+            move |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .confusable()
+                {
+                    error.add("confusable");
+                } else if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .confusable_with(&reserved)
+                {
+                    error.add(&code);
+                }
+            },
+        )
+    }};
+}
+
+#[macro_export]
+macro_rules! rule_no_html {
     ($name:ident) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
-                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.phone() {
-                    error.add("phone");
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .no_html()
+                {
+                    error.add("no_html");
                 }
             },
         )
@@ -80,17 +1168,17 @@ macro_rules! rule_phone {
 }
 
 #[macro_export]
-macro_rules! rule_non_control_character {
-    ($name:ident) => {
+macro_rules! rule_contains {
+    ($name:ident, $needle:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
                 if $crate::wrappers::rules::SomeOrString(&obj.$name)
                     .0
-                    .non_control_character()
+                    .rule_contains($needle)
                 {
-                    error.add("non_control_character");
+                    error.add(&format!("contains:{}", $needle));
                 }
             },
         )
@@ -98,14 +1186,25 @@ macro_rules! rule_non_control_character {
 }
 
 #[macro_export]
-macro_rules! rule_ip {
-    ($name:ident) => {
+macro_rules! rule_starts_with {
+    ($name:ident, $needle:expr) => {
+        $crate::rule_starts_with!(@rule $name, $needle, false)
+    };
+
+    ($name:ident, $needle:expr, case_insensitive) => {
+        $crate::rule_starts_with!(@rule $name, $needle, true)
+    };
+
+    (@rule $name:ident, $needle:expr, $case_insensitive:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
-                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip() {
-                    error.add("ip");
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .rule_starts_with($needle.to_string(), $case_insensitive)
+                {
+                    error.add(&format!("starts_with:{}", $needle));
                 }
             },
         )
@@ -113,14 +1212,25 @@ macro_rules! rule_ip {
 }
 
 #[macro_export]
-macro_rules! rule_ip_v4 {
-    ($name:ident) => {
+macro_rules! rule_ends_with {
+    ($name:ident, $needle:expr) => {
+        $crate::rule_ends_with!(@rule $name, $needle, false)
+    };
+
+    ($name:ident, $needle:expr, case_insensitive) => {
+        $crate::rule_ends_with!(@rule $name, $needle, true)
+    };
+
+    (@rule $name:ident, $needle:expr, $case_insensitive:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
-                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip_v4() {
-                    error.add("ip_v4");
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .rule_ends_with($needle.to_string(), $case_insensitive)
+                {
+                    error.add(&format!("ends_with:{}", $needle));
                 }
             },
         )
@@ -128,14 +1238,25 @@ macro_rules! rule_ip_v4 {
 }
 
 #[macro_export]
-macro_rules! rule_ip_v6 {
-    ($name:ident) => {
+macro_rules! rule_not_contains {
+    ($name:ident, $needle:expr) => {
+        $crate::rule_not_contains!(@rule $name, $needle, false)
+    };
+
+    ($name:ident, $needle:expr, case_insensitive) => {
+        $crate::rule_not_contains!(@rule $name, $needle, true)
+    };
+
+    (@rule $name:ident, $needle:expr, $case_insensitive:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
-                if $crate::wrappers::rules::SomeOrString(&obj.$name).0.ip_v6() {
-                    error.add("ip_v6");
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .rule_not_contains($needle.to_string(), $case_insensitive)
+                {
+                    error.add(&format!("not_contains:{}", $needle));
                 }
             },
         )
@@ -143,17 +1264,29 @@ macro_rules! rule_ip_v6 {
 }
 
 #[macro_export]
-macro_rules! rule_credit_card {
-    ($name:ident) => {
+macro_rules! rule_contains_any {
+    ($name:ident, $items:expr) => {
+        $crate::rule_contains_any!(@rule $name, $items, false)
+    };
+
+    ($name:ident, $items:expr, case_insensitive) => {
+        $crate::rule_contains_any!(@rule $name, $items, true)
+    };
+
+    (@rule $name:ident, $items:expr, $case_insensitive:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
                 if $crate::wrappers::rules::SomeOrString(&obj.$name)
                     .0
-                    .credit_card()
+                    .rule_contains_any($items, $case_insensitive)
                 {
-                    error.add("credit_card");
+                    let string_items = $items
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>();
+                    error.add(&format!("contains_any:{}", string_items.join(",")));
                 }
             },
         )
@@ -161,17 +1294,29 @@ macro_rules! rule_credit_card {
 }
 
 #[macro_export]
-macro_rules! rule_contains {
-    ($name:ident, $needle:expr) => {
+macro_rules! rule_contains_all {
+    ($name:ident, $items:expr) => {
+        $crate::rule_contains_all!(@rule $name, $items, false)
+    };
+
+    ($name:ident, $items:expr, case_insensitive) => {
+        $crate::rule_contains_all!(@rule $name, $items, true)
+    };
+
+    (@rule $name:ident, $items:expr, $case_insensitive:expr) => {
         $crate::Rule::new(
             stringify!($name),
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrStringWrapper;
                 if $crate::wrappers::rules::SomeOrString(&obj.$name)
                     .0
-                    .rule_contains($needle)
+                    .rule_contains_all($items, $case_insensitive)
                 {
-                    error.add(&format!("contains:{}", $needle));
+                    let string_items = $items
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>();
+                    error.add(&format!("contains_all:{}", string_items.join(",")));
                 }
             },
         )
@@ -216,6 +1361,26 @@ macro_rules! rule_not_equalt_to {
 
 #[macro_export]
 macro_rules! rule_in {
+    ($name:ident, variants = $type:ty) => {
+        $crate::rule_in!(
+            @rule $name,
+            <$type as $crate::strum::VariantNames>::VARIANTS.to_vec(),
+            false
+        )
+    };
+
+    ($name:ident, variants = $type:ty, case_insensitive) => {
+        $crate::rule_in!(
+            @rule $name,
+            <$type as $crate::strum::VariantNames>::VARIANTS.to_vec(),
+            true
+        )
+    };
+
+    ($name:ident, $items:expr, case_insensitive) => {
+        $crate::rule_in!(@rule $name, $items, true)
+    };
+
     ($name:ident, $items:expr) => {
         $crate::Rule::new(
             stringify!($name),
@@ -234,6 +1399,71 @@ macro_rules! rule_in {
             },
         )
     };
+
+    (@rule $name:ident, $items:expr, $case_insensitive:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .in_with($items, $case_insensitive)
+                {
+                    let string_items = $items
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>();
+                    error.add(&format!("in:{}", string_items.join(",")));
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_not_in {
+    ($name:ident, variants = $type:ty) => {
+        $crate::rule_not_in!(
+            @rule $name,
+            <$type as $crate::strum::VariantNames>::VARIANTS.to_vec(),
+            false
+        )
+    };
+
+    ($name:ident, variants = $type:ty, case_insensitive) => {
+        $crate::rule_not_in!(
+            @rule $name,
+            <$type as $crate::strum::VariantNames>::VARIANTS.to_vec(),
+            true
+        )
+    };
+
+    ($name:ident, $items:expr, case_insensitive) => {
+        $crate::rule_not_in!(@rule $name, $items, true)
+    };
+
+    ($name:ident, $items:expr) => {
+        $crate::rule_not_in!(@rule $name, $items, false)
+    };
+
+    (@rule $name:ident, $items:expr, $case_insensitive:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrStringWrapper;
+                if $crate::wrappers::rules::SomeOrString(&obj.$name)
+                    .0
+                    .not_in($items, $case_insensitive)
+                {
+                    let string_items = $items
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<String>>();
+                    error.add(&format!("not_in:{}", string_items.join(",")));
+                }
+            },
+        )
+    };
 }
 
 #[macro_export]
@@ -316,6 +1546,11 @@ macro_rules! rule_range {
             |obj: &Self, error: &mut $crate::error::ValidationError| {
                 use $crate::wrappers::rules::SomeOrNumberWrapper;
                 if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .numeric()
+                {
+                    error.add("numeric");
+                } else if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
                     .0
                     .range($min, $max)
                 {
@@ -341,9 +1576,137 @@ macro_rules! rule_range {
                 use $crate::wrappers::rules::SomeOrNumberWrapper;
                 if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
                     .0
-                    .range(Some($min), None)
+                    .numeric()
+                {
+                    error.add("numeric");
+                } else if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .range(Some($min), None::<f64>)
+                {
+                    error.add(&format!("range:{}-+inf", $min));
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_positive {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .positive()
+                {
+                    error.add("positive");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_non_negative {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .non_negative()
+                {
+                    error.add("non_negative");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_negative {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .negative()
+                {
+                    error.add("negative");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_multiple_of {
+    ($name:ident, $step:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .multiple_of($step)
+                {
+                    error.add(&format!("multiple_of:{}", $step));
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_decimal_places {
+    ($name:ident, $max:expr) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .decimal_places($max)
+                {
+                    error.add(&format!("decimal_places:{}", $max));
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_finite {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name).0.finite() {
+                    error.add("finite");
+                }
+            },
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! rule_integer {
+    ($name:ident) => {
+        $crate::Rule::new(
+            stringify!($name),
+            |obj: &Self, error: &mut $crate::error::ValidationError| {
+                use $crate::wrappers::rules::SomeOrNumberWrapper;
+                if $crate::wrappers::rules::SomeOrNumber(&obj.$name)
+                    .0
+                    .integer()
                 {
-                    error.add(format!("range:{}-{}", $min, "inf"));
+                    error.add("integer");
                 }
             },
         )
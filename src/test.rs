@@ -1,4 +1,8 @@
 use super::*;
+use crate::error::ValidationError;
+use crate::helpers::geo::BoundingBox;
+use crate::helpers::html::HtmlPolicy;
+use crate::helpers::password::PasswordPolicy;
 use actix_web::{http, web, HttpResponse, ResponseError};
 use serde::Deserialize;
 
@@ -236,10 +240,11 @@ fn test_try_to_pass_rule_in() {
 
     match obj.validate() {
         Ok(_) => (),
-        Err(e) => match e.get_error("email") {
-            Ok(e) => assert!(!e.contains("in")),
-            Err(_) => (),
-        },
+        Err(e) => {
+            if let Ok(e) = e.get_error("email") {
+                assert!(!e.contains("in"))
+            }
+        }
     };
 }
 
@@ -416,14 +421,13 @@ fn test_if_ips_are_ok() {
 
     match obj.validate() {
         Ok(_) => panic!("Was expected to validate IP all properties"),
-        Err(e) => match e.get_error("ip_v6") {
-            Ok(e) => {
+        Err(e) => {
+            if let Ok(e) = e.get_error("ip_v6") {
                 assert!(!e.contains("ip"));
                 assert!(!e.contains("ip_v4"));
                 assert!(!e.contains("ip_v6"));
             }
-            Err(_) => (),
-        },
+        }
     };
 }
 
@@ -442,12 +446,11 @@ fn test_equal_to_rule() {
 
     match obj.validate() {
         Ok(_) => panic!("Was expected to validate IP all properties"),
-        Err(e) => match e.get_error("ip") {
-            Ok(e) => {
+        Err(e) => {
+            if let Ok(e) = e.get_error("ip") {
                 assert!(e.contains("equalt_to:ip!=ip_v4"))
             }
-            Err(_) => (),
-        },
+        }
     };
 }
 
@@ -466,12 +469,11 @@ fn test_not_equal_to_rule() {
 
     match obj.validate() {
         Ok(_) => panic!("Was expected to validate IP all properties"),
-        Err(e) => match e.get_error("ip") {
-            Ok(e) => {
+        Err(e) => {
+            if let Ok(e) = e.get_error("ip") {
                 assert!(e.contains("not_equalt_to:ip==ip_v6"))
             }
-            Err(_) => (),
-        },
+        }
     };
 }
 
@@ -490,12 +492,11 @@ fn test_fail_accepted_rule_in_option() {
 
     match obj.validate() {
         Ok(_) => panic!("Was expected to validate agree_first"),
-        Err(e) => match e.get_error("agree_first") {
-            Ok(e) => {
+        Err(e) => {
+            if let Ok(e) = e.get_error("agree_first") {
                 assert!(e.contains("accepted"))
             }
-            Err(_) => (),
-        },
+        }
     };
 }
 
@@ -514,11 +515,1750 @@ fn test_fail_accepted_rule() {
 
     match obj.validate() {
         Ok(_) => panic!("Was expected to validate agree_second"),
-        Err(e) => match e.get_error("agree_second") {
-            Ok(e) => {
+        Err(e) => {
+            if let Ok(e) = e.get_error("agree_second") {
                 assert!(e.contains("accepted"))
             }
-            Err(_) => (),
-        },
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestDevice {
+    pub mac: Option<String>,
+    pub port: Option<u16>,
+    pub address: Option<String>,
+}
+
+impl Validation for TestDevice {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_normalize_mac!(mac)]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_mac_eui48!(mac),
+            rule_port!(port),
+            rule_socket_address!(address),
+        ]
+    }
+}
+
+#[test]
+fn test_it_will_normalize_mac() {
+    let obj = TestDevice {
+        mac: Some("001A.2B3C.4D5E".to_string()),
+        port: Some(8080),
+        address: Some("localhost:8080".to_string()),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.mac, Some("00:1a:2b:3c:4d:5e".to_string()));
+}
+
+#[test]
+fn test_device_rules_fail() {
+    let obj = TestDevice {
+        mac: Some("00:1a:2b:3c:4d:5e:6f:70".to_string()),
+        port: Some(0),
+        address: Some("localhost".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate device properties"),
+        Err(e) => {
+            assert!(e.get_error("mac").unwrap().contains("mac_eui48"));
+            assert!(e.get_error("port").unwrap().contains("port"));
+            assert!(e.get_error("address").unwrap().contains("socket_address"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestContact {
+    pub phone: Option<String>,
+    pub mobile: String,
+}
+
+impl Validation for TestContact {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_phone_e164!(phone, "HR"),
+            modifier_phone_national!(mobile, "HR"),
+        ]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_phone!(phone, region = "HR", countries = ["HR"]),
+            rule_phone!(mobile, region = "HR", types = [Mobile]),
+        ]
+    }
+}
+
+#[test]
+fn test_phone_with_region_passes() {
+    let obj = TestContact {
+        phone: Some("01 4567 890".to_string()),
+        mobile: "091 234 5678".to_string(),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_phone_with_region_fails() {
+    let obj = TestContact {
+        phone: Some("+33642926829".to_string()),
+        mobile: "01 4567 890".to_string(),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate phone properties"),
+        Err(e) => {
+            assert!(e.get_error("phone").unwrap().contains("phone_country:FR"));
+            assert!(e
+                .get_error("mobile")
+                .unwrap()
+                .contains("phone_type:fixed_line"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestUnknownPhoneRegion {
+    pub phone: String,
+}
+
+impl Validation for TestUnknownPhoneRegion {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_phone_e164!(phone, "XX")]
+    }
+}
+
+#[test]
+#[should_panic(expected = "Unknown phone region `XX`")]
+fn test_phone_modifier_unknown_region() {
+    let obj = TestUnknownPhoneRegion {
+        phone: "091 234 5678".to_string(),
+    };
+
+    let _ = obj.validate();
+}
+
+#[test]
+fn test_it_will_format_phone() {
+    let obj = TestContact {
+        phone: Some("00385 1 4567 890".to_string()),
+        mobile: "+385 (91) 234-5678".to_string(),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.phone, Some("+38514567890".to_string()));
+    assert_eq!(response.mobile, "091 234 5678".to_string());
+}
+
+#[test]
+fn test_it_will_leave_invalid_phone_untouched() {
+    let obj = TestContact {
+        phone: Some("not a phone".to_string()),
+        mobile: "091 234 5678".to_string(),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate phone property"),
+        Err(e) => assert_eq!(
+            e.get_error("phone").unwrap().get_errors(),
+            vec!["phone".to_string()]
+        ),
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestPayment {
+    pub card_number: Option<String>,
+    pub cvv: Option<String>,
+    pub expiry_month: Option<u8>,
+    pub expiry_year: Option<u16>,
+}
+
+impl Validation for TestPayment {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_normalize_credit_card!(card_number)]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_credit_card!(card_number, brands = [Visa, MasterCard]),
+            rule_cvv!(cvv, card_number),
+            rule_card_expiry!(expiry_month, expiry_year),
+        ]
+    }
+}
+
+#[test]
+fn test_payment_passes() {
+    let obj = TestPayment {
+        card_number: Some("4539 5711 4764 7251".to_string()),
+        cvv: Some("123".to_string()),
+        expiry_month: Some(1),
+        expiry_year: Some(9999),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.card_number, Some("4539571147647251".to_string()));
+}
+
+#[test]
+fn test_payment_fails() {
+    let obj = TestPayment {
+        card_number: Some("3433-804407-54432".to_string()),
+        cvv: Some("123".to_string()),
+        expiry_month: Some(12),
+        expiry_year: Some(2000),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate payment properties"),
+        Err(e) => {
+            assert!(e
+                .get_error("card_number")
+                .unwrap()
+                .contains("credit_card_brand:amex"));
+            assert!(e.get_error("cvv").unwrap().contains("cvv"));
+            assert!(e.get_error("expiry_month").unwrap().contains("card_expiry"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestPayout {
+    pub iban: Option<String>,
+    pub bic: String,
+}
+
+impl Validation for TestPayout {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_normalize_iban!(iban)]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_iban!(iban), rule_bic!(bic)]
+    }
+}
+
+#[test]
+fn test_payout_passes() {
+    let obj = TestPayout {
+        iban: Some("hr12 1001 0051 8630 0016 0".to_string()),
+        bic: "ZABAHR2X".to_string(),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.iban, Some("HR1210010051863000160".to_string()));
+}
+
+#[test]
+fn test_payout_fails() {
+    let obj = TestPayout {
+        iban: Some("HR1210010051863000161".to_string()),
+        bic: "ZABAHR2".to_string(),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate payout properties"),
+        Err(e) => {
+            assert!(e.get_error("iban").unwrap().contains("iban"));
+            assert!(e.get_error("bic").unwrap().contains("bic"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestCompany {
+    pub vat_id: Option<String>,
+    pub country: Option<String>,
+    pub oib: String,
+}
+
+impl Validation for TestCompany {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_vat!(vat_id, country), rule_vat!(oib, "HR")]
+    }
+}
+
+#[test]
+fn test_vat_passes() {
+    let obj = TestCompany {
+        vat_id: Some("DE136695976".to_string()),
+        country: Some("DE".to_string()),
+        oib: "69435151530".to_string(),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_vat_fails() {
+    let obj = TestCompany {
+        vat_id: Some("DE136695976".to_string()),
+        country: Some("IT".to_string()),
+        oib: "69435151531".to_string(),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate VAT properties"),
+        Err(e) => {
+            assert!(e.get_error("vat_id").unwrap().contains("vat"));
+            assert!(e.get_error("oib").unwrap().contains("vat"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestAddress {
+    pub zip: Option<String>,
+    pub country: Option<String>,
+    pub croatian_zip: String,
+}
+
+impl Validation for TestAddress {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_normalize_postal_code!(zip, country)]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_postal_code!(zip, country),
+            rule_postal_code!(croatian_zip, "HR"),
+        ]
+    }
+}
+
+#[test]
+fn test_postal_code_passes() {
+    let obj = TestAddress {
+        zip: Some("sw1a1aa".to_string()),
+        country: Some("GB".to_string()),
+        croatian_zip: "10000".to_string(),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.zip, Some("SW1A 1AA".to_string()));
+}
+
+#[test]
+fn test_postal_code_fails() {
+    let obj = TestAddress {
+        zip: Some("sw1a1aa".to_string()),
+        country: Some("US".to_string()),
+        croatian_zip: "1000".to_string(),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate postal code properties"),
+        Err(e) => {
+            assert!(e.get_error("zip").unwrap().contains("postal_code"));
+            assert!(e.get_error("croatian_zip").unwrap().contains("postal_code"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestSignup {
+    pub username: String,
+    pub email: Option<String>,
+    pub password: Option<String>,
+}
+
+impl Validation for TestSignup {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        let policy = PasswordPolicy::new()
+            .min_length(8)
+            .uppercase()
+            .digit()
+            .max_sequential(3);
+
+        vec![rule_password!(password, policy, [username, email])]
+    }
+}
+
+#[test]
+fn test_password_passes() {
+    let obj = TestSignup {
+        username: "john.doe".to_string(),
+        email: Some("john.doe@example.com".to_string()),
+        password: Some("Tr0ub4dor&3".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_password_fails() {
+    let obj = TestSignup {
+        username: "john.doe".to_string(),
+        email: Some("john.doe@example.com".to_string()),
+        password: Some("abcde".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate password properties"),
+        Err(e) => {
+            let errors = e.get_error("password").unwrap();
+
+            assert!(errors.contains("password_min_length:8"));
+            assert!(errors.contains("password_uppercase"));
+            assert!(errors.contains("password_digit"));
+            assert!(errors.contains("password_sequential:3"));
+        }
     };
 }
+
+#[test]
+fn test_password_matches_other_fields() {
+    let obj = TestSignup {
+        username: "Jo4nDoe99".to_string(),
+        email: Some("Jo4nDoe99@example.com".to_string()),
+        password: Some("Jo4nDoe99".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate password properties"),
+        Err(e) => {
+            let errors = e.get_error("password").unwrap();
+
+            assert!(errors.contains("password_matches:username"));
+            assert!(errors.contains("password_matches:email"));
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "country-codes",
+    feature = "currency-codes",
+    feature = "language-tags",
+    feature = "timezones"
+))]
+#[derive(Clone, Deserialize, Debug)]
+struct TestLocale {
+    pub country: Option<String>,
+    pub country_alpha3: Option<String>,
+    pub currency: Option<String>,
+    pub language: String,
+    pub timezone: Option<String>,
+}
+
+#[cfg(all(
+    feature = "country-codes",
+    feature = "currency-codes",
+    feature = "language-tags",
+    feature = "timezones"
+))]
+impl Validation for TestLocale {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_country_code!(country, alpha2),
+            rule_country_code!(country_alpha3, alpha3),
+            rule_currency_code!(currency),
+            rule_language_tag!(language),
+            rule_timezone!(timezone),
+        ]
+    }
+}
+
+#[cfg(all(
+    feature = "country-codes",
+    feature = "currency-codes",
+    feature = "language-tags",
+    feature = "timezones"
+))]
+#[test]
+fn test_iso_codes_pass() {
+    let obj = TestLocale {
+        country: Some("HR".to_string()),
+        country_alpha3: Some("HRV".to_string()),
+        currency: Some("EUR".to_string()),
+        language: "hr-HR".to_string(),
+        timezone: Some("Europe/Zagreb".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[cfg(all(
+    feature = "country-codes",
+    feature = "currency-codes",
+    feature = "language-tags",
+    feature = "timezones"
+))]
+#[test]
+fn test_iso_codes_fail() {
+    let obj = TestLocale {
+        country: Some("HRV".to_string()),
+        country_alpha3: Some("HR".to_string()),
+        currency: Some("XYZ".to_string()),
+        language: "hr_HR".to_string(),
+        timezone: Some("Europe/Atlantis".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate ISO code properties"),
+        Err(e) => {
+            assert!(e
+                .get_error("country")
+                .unwrap()
+                .contains("country_code_alpha2"));
+            assert!(e
+                .get_error("country_alpha3")
+                .unwrap()
+                .contains("country_code_alpha3"));
+            assert!(e.get_error("currency").unwrap().contains("currency_code"));
+            assert!(e.get_error("language").unwrap().contains("language_tag"));
+            assert!(e.get_error("timezone").unwrap().contains("timezone"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestLocation {
+    pub lat: Option<f64>,
+    pub lng: Option<String>,
+    pub location: String,
+    pub point: Option<String>,
+}
+
+impl Validation for TestLocation {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        let croatia = BoundingBox::new(42.39, 13.49, 46.55, 19.45);
+
+        vec![
+            rule_latitude!(lat),
+            rule_longitude!(lng),
+            rule_lat_lng!(location),
+            rule_geojson_point!(point),
+            rule_bounding_box!(location, croatia),
+            rule_bounding_box!(lat, lng, croatia),
+        ]
+    }
+}
+
+#[test]
+fn test_location_passes() {
+    let obj = TestLocation {
+        lat: Some(45.81),
+        lng: Some("15.98".to_string()),
+        location: "45.81,15.98".to_string(),
+        point: Some(r#"{"type": "Point", "coordinates": [15.98, 45.81]}"#.to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_location_fails() {
+    let obj = TestLocation {
+        lat: Some(95.0),
+        lng: Some("east".to_string()),
+        location: "48.21,16.37".to_string(),
+        point: Some("45.81,15.98".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate location properties"),
+        Err(e) => {
+            assert!(e.get_error("lat").unwrap().contains("latitude"));
+            assert!(e.get_error("lat").unwrap().contains("bounding_box"));
+            assert!(e.get_error("lng").unwrap().contains("longitude"));
+            assert!(e.get_error("location").unwrap().contains("bounding_box"));
+            assert!(e.get_error("point").unwrap().contains("geojson_point"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestSettings {
+    #[allow(dead_code)]
+    pub theme: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestUpload {
+    pub image: Option<String>,
+    pub token: Option<String>,
+    pub checksum: String,
+    pub metadata: Option<String>,
+    pub settings: Option<String>,
+    pub jwt: Option<String>,
+}
+
+impl Validation for TestUpload {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_base64!(image, max_size = 8),
+            rule_base64!(token, url_safe = true, padded = false),
+            rule_hex!(checksum, 4),
+            rule_json!(metadata),
+            rule_json!(settings, TestSettings),
+            rule_jwt!(jwt),
+        ]
+    }
+}
+
+#[test]
+fn test_encoding_passes() {
+    let obj = TestUpload {
+        image: Some("aGVsbG8=".to_string()),
+        token: Some("-_8".to_string()),
+        checksum: "deadbeef".to_string(),
+        metadata: Some("[1, 2, 3]".to_string()),
+        settings: Some(r#"{"theme": "dark"}"#.to_string()),
+        jwt: Some("eyJhbGciOiJub25lIn0.eyJzdWIiOiIxMjMifQ.".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_encoding_fails() {
+    let obj = TestUpload {
+        image: Some("aGVsbG8gd29ybGQ=".to_string()),
+        token: Some("+/8=".to_string()),
+        checksum: "deadbeefdead".to_string(),
+        metadata: Some("{".to_string()),
+        settings: Some(r#"{"font": "mono"}"#.to_string()),
+        jwt: Some("a.b.c".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate encoding properties"),
+        Err(e) => {
+            assert!(e.get_error("image").unwrap().contains("base64_max_size:8"));
+            assert!(e.get_error("token").unwrap().contains("base64"));
+            assert!(e.get_error("checksum").unwrap().contains("hex_bytes:4"));
+            assert!(e.get_error("metadata").unwrap().contains("json"));
+            assert!(e.get_error("settings").unwrap().contains("json"));
+            assert!(e.get_error("jwt").unwrap().contains("jwt"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestPlugin {
+    pub version: String,
+    pub engine: Option<String>,
+    pub slug: Option<String>,
+    pub key: Option<String>,
+    pub color: Option<String>,
+}
+
+impl Validation for TestPlugin {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_semver!(version),
+            rule_semver!(engine, ">=1.2"),
+            rule_slug!(slug),
+            rule_slug!(key, '_'),
+            rule_css_color!(color),
+        ]
+    }
+}
+
+#[test]
+fn test_plugin_passes() {
+    let obj = TestPlugin {
+        version: "1.0.0-beta.1".to_string(),
+        engine: Some("1.4.2".to_string()),
+        slug: Some("my-plugin".to_string()),
+        key: Some("my_plugin".to_string()),
+        color: Some("#ff0000".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_plugin_fails() {
+    let obj = TestPlugin {
+        version: "1.0".to_string(),
+        engine: Some("1.1.0".to_string()),
+        slug: Some("My Plugin".to_string()),
+        key: Some("my-plugin".to_string()),
+        color: Some("red".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate plugin properties"),
+        Err(e) => {
+            assert!(e.get_error("version").unwrap().contains("semver"));
+            assert!(e.get_error("engine").unwrap().contains("semver_req:>=1.2"));
+            assert!(e.get_error("slug").unwrap().contains("slug"));
+            assert!(e.get_error("key").unwrap().contains("slug"));
+            assert!(e.get_error("color").unwrap().contains("css_color"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestProduct {
+    pub isbn: Option<String>,
+    pub issn: Option<String>,
+    pub ean: String,
+    pub upc: Option<String>,
+    pub gtin: Option<String>,
+}
+
+impl Validation for TestProduct {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![modifier_isbn13!(isbn)]
+    }
+
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_isbn13!(isbn),
+            rule_issn!(issn),
+            rule_ean13!(ean),
+            rule_upc_a!(upc),
+            rule_gtin!(gtin),
+        ]
+    }
+}
+
+#[test]
+fn test_product_passes() {
+    let obj = TestProduct {
+        isbn: Some("0-306-40615-2".to_string()),
+        issn: Some("0378-5955".to_string()),
+        ean: "4006381333931".to_string(),
+        upc: Some("036000291452".to_string()),
+        gtin: Some("10012345000017".to_string()),
+    };
+
+    let response = obj.validate().unwrap();
+
+    assert_eq!(response.isbn, Some("9780306406157".to_string()));
+}
+
+#[test]
+fn test_product_fails() {
+    let obj = TestProduct {
+        isbn: Some("0-306-40615-3".to_string()),
+        issn: Some("0378-5956".to_string()),
+        ean: "4006381333932".to_string(),
+        upc: Some("4006381333931".to_string()),
+        gtin: Some("400638133393".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate product properties"),
+        Err(e) => {
+            assert!(e.get_error("isbn").unwrap().contains("isbn13"));
+            assert!(e.get_error("issn").unwrap().contains("issn"));
+            assert!(e.get_error("ean").unwrap().contains("ean13"));
+            assert!(e.get_error("upc").unwrap().contains("upc_a"));
+            assert!(e.get_error("gtin").unwrap().contains("gtin"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestListing {
+    pub sku: Option<String>,
+    pub email: String,
+    pub contact: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Validation for TestListing {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_starts_with!(sku, "SKU-"),
+            rule_ends_with!(email, "@company.com", case_insensitive),
+            rule_not_contains!(contact, "@example.com", case_insensitive),
+            rule_contains_any!(title, vec!["sale", "discount"], case_insensitive),
+            rule_contains_all!(description, vec!["size", "color"]),
+        ]
+    }
+}
+
+#[test]
+fn test_affix_passes() {
+    let obj = TestListing {
+        sku: Some("SKU-1234".to_string()),
+        email: "John@Company.com".to_string(),
+        contact: Some("john@company.com".to_string()),
+        title: Some("Summer SALE".to_string()),
+        description: Some("Pick a size and a color".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_affix_fails() {
+    let obj = TestListing {
+        sku: Some("sku-1234".to_string()),
+        email: "john@company.com.evil".to_string(),
+        contact: Some("john@EXAMPLE.com".to_string()),
+        title: Some("New arrivals".to_string()),
+        description: Some("Pick a size".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate affix properties"),
+        Err(e) => {
+            assert!(e.get_error("sku").unwrap().contains("starts_with:SKU-"));
+            assert!(e
+                .get_error("email")
+                .unwrap()
+                .contains("ends_with:@company.com"));
+            assert!(e
+                .get_error("contact")
+                .unwrap()
+                .contains("not_contains:@example.com"));
+            assert!(e
+                .get_error("title")
+                .unwrap()
+                .contains("contains_any:sale,discount"));
+            assert!(e
+                .get_error("description")
+                .unwrap()
+                .contains("contains_all:size,color"));
+        }
+    };
+}
+
+#[derive(Deserialize, strum::VariantNames)]
+#[strum(serialize_all = "snake_case")]
+#[allow(dead_code)]
+enum TestStatus {
+    Draft,
+    InReview,
+    Published,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestAccount {
+    pub username: String,
+    pub role: Option<String>,
+    pub status: Option<String>,
+    pub previous_status: Option<String>,
+}
+
+impl Validation for TestAccount {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_not_in!(username, vec!["admin", "root"], case_insensitive),
+            rule_in!(role, vec!["Editor", "Viewer"], case_insensitive),
+            rule_in!(status, variants = TestStatus),
+            rule_not_in!(previous_status, variants = TestStatus, case_insensitive),
+        ]
+    }
+}
+
+#[test]
+fn test_in_passes() {
+    let obj = TestAccount {
+        username: "john".to_string(),
+        role: Some("editor".to_string()),
+        status: Some("in_review".to_string()),
+        previous_status: Some("archived".to_string()),
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_in_fails() {
+    let obj = TestAccount {
+        username: "Admin".to_string(),
+        role: Some("owner".to_string()),
+        status: Some("InReview".to_string()),
+        previous_status: Some("DRAFT".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate in properties"),
+        Err(e) => {
+            assert!(e
+                .get_error("username")
+                .unwrap()
+                .contains("not_in:admin,root"));
+            assert!(e.get_error("role").unwrap().contains("in:Editor,Viewer"));
+            assert!(e
+                .get_error("status")
+                .unwrap()
+                .contains("in:draft,in_review,published"));
+            assert!(e
+                .get_error("previous_status")
+                .unwrap()
+                .contains("not_in:draft,in_review,published"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestOrder {
+    pub quantity: u32,
+    pub price: Option<f64>,
+    pub discount: Option<f64>,
+    pub adjustment: i32,
+    pub weight: f32,
+}
+
+impl Validation for TestOrder {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_positive!(quantity),
+            rule_multiple_of!(quantity, 6),
+            rule_non_negative!(price),
+            rule_decimal_places!(price, 2),
+            rule_multiple_of!(discount, 0.05),
+            rule_negative!(adjustment),
+            rule_finite!(weight),
+            rule_integer!(weight),
+        ]
+    }
+}
+
+#[test]
+fn test_number_rules_pass() {
+    let obj = TestOrder {
+        quantity: 12,
+        price: Some(19.99),
+        discount: None,
+        adjustment: -5,
+        weight: 3.0,
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_number_rules_fail() {
+    let obj = TestOrder {
+        quantity: 0,
+        price: Some(-1.999),
+        discount: Some(0.12),
+        adjustment: 0,
+        weight: f32::NAN,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate number properties"),
+        Err(e) => {
+            assert!(e.get_error("quantity").unwrap().contains("positive"));
+            assert!(e.get_error("price").unwrap().contains("non_negative"));
+            assert!(e.get_error("price").unwrap().contains("decimal_places:2"));
+            assert!(e
+                .get_error("discount")
+                .unwrap()
+                .contains("multiple_of:0.05"));
+            assert!(e.get_error("adjustment").unwrap().contains("negative"));
+            assert!(e.get_error("weight").unwrap().contains("finite"));
+            assert!(e.get_error("weight").unwrap().contains("integer"));
+        }
+    };
+
+    let obj = TestOrder {
+        quantity: 8,
+        price: None,
+        discount: None,
+        adjustment: -1,
+        weight: 2.5,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate number properties"),
+        Err(e) => {
+            assert!(e.get_error("quantity").unwrap().contains("multiple_of:6"));
+            assert!(e.get_error("weight").unwrap().contains("integer"));
+            assert!(!e.get_error("weight").unwrap().contains("finite"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestMeasurement {
+    pub price: f32,
+    pub step: Option<f32>,
+    pub ratio: Option<f32>,
+}
+
+impl Validation for TestMeasurement {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_decimal_places!(price, 2),
+            rule_decimal_places!(step, 1),
+            rule_multiple_of!(step, 0.1),
+            rule_range!(ratio, Some(0), Some(0.1)),
+        ]
+    }
+}
+
+#[test]
+fn test_number_rules_on_f32_pass() {
+    let obj = TestMeasurement {
+        price: 19.99,
+        step: Some(0.3),
+        ratio: Some(0.1),
+    };
+
+    assert!(obj.validate().is_ok());
+
+    let obj = TestMeasurement {
+        price: 20.0,
+        step: None,
+        ratio: None,
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_number_rules_on_f32_fail() {
+    let obj = TestMeasurement {
+        price: 19.999,
+        step: Some(0.35),
+        ratio: Some(0.2),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate f32 number properties"),
+        Err(e) => {
+            assert!(e.get_error("price").unwrap().contains("decimal_places:2"));
+            assert!(e.get_error("step").unwrap().contains("decimal_places:1"));
+            assert!(e.get_error("step").unwrap().contains("multiple_of:0.1"));
+            assert!(e.get_error("ratio").unwrap().contains("range:0-0.1"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestCounters {
+    pub views: Option<u64>,
+    pub balance: Option<i64>,
+    pub index: Option<usize>,
+    pub offset: Option<isize>,
+}
+
+impl Validation for TestCounters {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_positive!(views),
+            rule_multiple_of!(views, 10),
+            rule_range!(balance, Some(-100), Some(100)),
+            rule_non_negative!(index),
+            rule_negative!(offset),
+        ]
+    }
+}
+
+#[test]
+fn test_number_rules_on_optional_wide_integers_pass() {
+    let obj = TestCounters {
+        views: Some(20),
+        balance: Some(-50),
+        index: Some(0),
+        offset: Some(-1),
+    };
+
+    assert!(obj.validate().is_ok());
+
+    let obj = TestCounters {
+        views: None,
+        balance: None,
+        index: None,
+        offset: None,
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_number_rules_on_optional_wide_integers_fail() {
+    let obj = TestCounters {
+        views: Some(0),
+        balance: Some(101),
+        index: Some(usize::MAX),
+        offset: Some(1),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate optional integer properties"),
+        Err(e) => {
+            assert!(e.get_error("views").unwrap().contains("positive"));
+            assert!(e.get_error("balance").unwrap().contains("range:-100-100"));
+            assert!(e.get_error("index").is_err());
+            assert!(e.get_error("offset").unwrap().contains("negative"));
+        }
+    };
+
+    let obj = TestCounters {
+        views: Some(15),
+        balance: None,
+        index: None,
+        offset: None,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate optional integer properties"),
+        Err(e) => {
+            assert!(e.get_error("views").unwrap().contains("multiple_of:10"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestLegacyOrder {
+    pub quantity: String,
+    pub price: Option<String>,
+    pub score: Option<String>,
+    pub discount: Option<String>,
+}
+
+impl Validation for TestLegacyOrder {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_integer_string!(quantity),
+            rule_range!(quantity, Some(1), Some(100)),
+            rule_decimal_string!(price),
+            rule_range!(price, 0),
+            rule_numeric!(score),
+            rule_range!(discount, Some(0), Some(50)),
+        ]
+    }
+}
+
+#[test]
+fn test_numeric_strings_pass() {
+    let obj = TestLegacyOrder {
+        quantity: "12".to_string(),
+        price: Some("19.99".to_string()),
+        score: Some("1e3".to_string()),
+        discount: None,
+    };
+
+    assert!(obj.validate().is_ok());
+}
+
+#[test]
+fn test_numeric_strings_fail() {
+    let obj = TestLegacyOrder {
+        quantity: "twelve".to_string(),
+        price: Some("-1e2".to_string()),
+        score: Some("NaN".to_string()),
+        discount: Some("75".to_string()),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate numeric string properties"),
+        Err(e) => {
+            let quantity = e.get_error("quantity").unwrap();
+            assert!(quantity.contains("integer_string"));
+            assert!(quantity.contains("numeric"));
+            assert!(!quantity.contains("range"));
+            assert!(e.get_error("price").unwrap().contains("decimal_string"));
+            assert!(e.get_error("price").unwrap().contains("range:0-+inf"));
+            assert!(e.get_error("score").unwrap().contains("numeric"));
+            assert!(e.get_error("discount").unwrap().contains("range:0-50"));
+        }
+    };
+}
+
+#[derive(Clone, Copy, Deserialize, Debug)]
+struct Celsius(f64);
+
+impl From<Celsius> for f64 {
+    fn from(c: Celsius) -> f64 {
+        c.0
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestThermostat {
+    pub target: Option<Celsius>,
+}
+
+impl Validation for TestThermostat {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_range!(target, Some(5), Some(30))]
+    }
+}
+
+#[test]
+fn test_range_rule_on_custom_number() {
+    let obj = TestThermostat {
+        target: Some(Celsius(21.5)),
+    };
+
+    assert!(obj.validate().is_ok());
+
+    let obj = TestThermostat {
+        target: Some(Celsius(35.0)),
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate target"),
+        Err(e) => assert!(e.get_error("target").unwrap().contains("range")),
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestForm {
+    pub quantity_raw: Option<String>,
+    pub quantity: Option<u32>,
+    pub price_raw: String,
+    pub price: f64,
+    pub ip_raw: Option<String>,
+    pub ip: Option<std::net::IpAddr>,
+    pub newsletter_raw: Option<String>,
+    pub newsletter: bool,
+}
+
+impl Validation for TestForm {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_required!(quantity),
+            rule_range!(quantity, Some(1), Some(10)),
+        ]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_coerce!(quantity_raw, quantity),
+            modifier_coerce!(price_raw, price),
+            modifier_coerce!(ip_raw, ip),
+            modifier_coerce_bool!(newsletter_raw, newsletter),
+        ]
+    }
+}
+
+#[test]
+fn test_coerce_modifiers_pass() {
+    let obj = TestForm {
+        quantity_raw: Some(" 3 ".to_string()),
+        quantity: None,
+        price_raw: "19.99".to_string(),
+        price: 0.0,
+        ip_raw: None,
+        ip: None,
+        newsletter_raw: Some("on".to_string()),
+        newsletter: false,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.quantity, Some(3));
+    assert_eq!(obj.price, 19.99);
+    assert_eq!(obj.ip, None);
+    assert!(obj.newsletter);
+}
+
+#[test]
+fn test_coerce_modifiers_fail() {
+    let obj = TestForm {
+        quantity_raw: Some("three".to_string()),
+        quantity: Some(5),
+        price_raw: "free".to_string(),
+        price: 0.0,
+        ip_raw: Some("localhost".to_string()),
+        ip: None,
+        newsletter_raw: Some("maybe".to_string()),
+        newsletter: false,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to report coercion errors"),
+        Err(e) => {
+            assert!(e.get_error("quantity").unwrap().contains("coerce"));
+            assert!(e.get_error("quantity").unwrap().contains("required"));
+            assert!(e.get_error("price").unwrap().contains("coerce"));
+            assert!(e.get_error("ip").unwrap().contains("coerce"));
+            assert!(e.get_error("newsletter").unwrap().contains("coerce_bool"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestPayload {
+    pub payload: Option<String>,
+    pub skip: bool,
+}
+
+impl Validation for TestPayload {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_required!(payload), rule_json!(payload)]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![Modifier::try_new(
+            "payload",
+            |obj: &mut Self, error: &mut ValidationError| {
+                if let Some(v) = &obj.payload {
+                    match crate::helpers::base64::decode_base64(v, &Default::default()) {
+                        Some(bytes) => obj.payload = String::from_utf8(bytes).ok(),
+                        None => {
+                            obj.payload = None;
+                            error.add("base64");
+                        }
+                    }
+                }
+            },
+        )]
+    }
+
+    fn skip_rules_on_modifier_error(&self) -> bool {
+        self.skip
+    }
+}
+
+#[test]
+fn test_fallible_modifier_passes() {
+    let obj = TestPayload {
+        payload: Some("eyJhIjogMX0=".to_string()),
+        skip: false,
+    };
+
+    assert_eq!(
+        obj.validate().unwrap().payload,
+        Some("{\"a\": 1}".to_string())
+    );
+}
+
+#[test]
+fn test_fallible_modifier_fails() {
+    let obj = TestPayload {
+        payload: Some("not base64!".to_string()),
+        skip: false,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to report modifier errors"),
+        Err(e) => {
+            let error = e.get_error("payload").unwrap();
+            assert!(error.contains("base64"));
+            assert!(error.contains("required"));
+            assert_eq!(error.len(), 2);
+        }
+    };
+
+    let obj = TestPayload {
+        payload: Some("not base64!".to_string()),
+        skip: true,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to report modifier errors"),
+        Err(e) => {
+            let error = e.get_error("payload").unwrap();
+            assert!(error.contains("base64"));
+            assert_eq!(error.len(), 1);
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestComment {
+    pub title: String,
+    pub body: Option<String>,
+    pub prefix: Option<String>,
+    pub suffix: String,
+}
+
+impl Validation for TestComment {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_remove_zero_width!(title),
+            modifier_collapse_whitespace!(title),
+            modifier_trim!(title),
+            modifier_strip_control_characters!(body),
+            modifier_normalize_newlines!(body),
+            modifier_trim_start!(prefix),
+            modifier_trim_end!(suffix),
+        ]
+    }
+}
+
+#[test]
+fn test_text_cleanup_modifiers() {
+    let obj = TestComment {
+        title: " \u{200B}Hello \t\n  world\u{FEFF} ".to_string(),
+        body: Some("First\u{0007} line\r\nSecond\tline\r".to_string()),
+        prefix: Some("  keep trailing  ".to_string()),
+        suffix: "  keep leading  ".to_string(),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.title, "Hello world");
+    assert_eq!(obj.body, Some("First line\nSecond\tline\n".to_string()));
+    assert_eq!(obj.prefix, Some("keep trailing  ".to_string()));
+    assert_eq!(obj.suffix, "  keep leading");
+
+    let obj = TestComment {
+        title: "\u{2060}Family \u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}".to_string(),
+        body: None,
+        prefix: None,
+        suffix: String::new(),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(
+        obj.title,
+        "Family \u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"
+    );
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestUsername {
+    pub username: String,
+    pub display_name: Option<String>,
+    pub bio: Option<String>,
+}
+
+impl Validation for TestUsername {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_confusable!(username, vec!["admin", "support"]),
+            rule_confusable!(display_name),
+        ]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_normalize!(username, NFKC),
+            modifier_case_fold!(username),
+            modifier_normalize!(bio, NFD),
+        ]
+    }
+}
+
+#[test]
+fn test_unicode_modifiers() {
+    let obj = TestUsername {
+        username: "ＳＴＲＡßＥ".to_string(),
+        display_name: Some("Stra\u{00DF}e".to_string()),
+        bio: Some("caf\u{00E9}".to_string()),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.username, "strasse");
+    assert_eq!(obj.bio, Some("cafe\u{0301}".to_string()));
+}
+
+#[test]
+fn test_confusable_fails() {
+    let obj = TestUsername {
+        username: "ａｄｍｉｎ".to_string(),
+        display_name: Some("p\u{0430}ypal".to_string()),
+        bio: None,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate confusable properties"),
+        Err(e) => {
+            assert!(e
+                .get_error("username")
+                .unwrap()
+                .contains("confusable:admin,support"));
+            assert!(e.get_error("display_name").unwrap().contains("confusable"));
+        }
+    };
+
+    let obj = TestUsername {
+        username: "adrnin".to_string(),
+        display_name: None,
+        bio: None,
+    };
+
+    assert!(obj.validate().is_err());
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestArticle {
+    pub author: String,
+    pub title: Option<String>,
+    pub slug: String,
+    pub column: String,
+    pub property: Option<String>,
+    pub css_class: Option<String>,
+}
+
+impl Validation for TestArticle {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_slug!(slug)]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_capitalize!(author),
+            modifier_title_case!(title),
+            modifier_slugify!(slug),
+            modifier_snake_case!(column),
+            modifier_camel_case!(property),
+            modifier_kebab_case!(css_class),
+        ]
+    }
+}
+
+#[test]
+fn test_case_modifiers() {
+    let obj = TestArticle {
+        author: "jOHN".to_string(),
+        title: Some("ten TIPS for better coffee".to_string()),
+        slug: "Čaj & kava: 10 savjeta".to_string(),
+        column: "createdAt".to_string(),
+        property: Some("created_at".to_string()),
+        css_class: Some("PrimaryButton".to_string()),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.author, "John");
+    assert_eq!(obj.title, Some("Ten Tips For Better Coffee".to_string()));
+    assert_eq!(obj.slug, "caj-kava-10-savjeta");
+    assert_eq!(obj.column, "created_at");
+    assert_eq!(obj.property, Some("createdAt".to_string()));
+    assert_eq!(obj.css_class, Some("primary-button".to_string()));
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestPost {
+    pub title: String,
+    pub summary: Option<String>,
+    pub body: String,
+    pub comment: Option<String>,
+    pub signature: Option<String>,
+}
+
+impl Validation for TestPost {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![rule_no_html!(summary)]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_strip_tags!(title),
+            modifier_sanitize_html!(body, HtmlPolicy::basic().tags(&["h2"])),
+            modifier_sanitize_html!(comment),
+            modifier_escape_html!(signature),
+        ]
+    }
+}
+
+#[test]
+fn test_html_modifiers() {
+    let obj = TestPost {
+        title: "<b>Hello</b> <script>alert(1)</script>world &amp; 1 < 2".to_string(),
+        summary: Some("1 < 2".to_string()),
+        body: "<h2 id=\"x\">Intro</h2><p onclick=\"x()\">Text <a href=\"javascript:x()\">link</a>"
+            .to_string(),
+        comment: Some("<h2>Big</h2> <em>small</em>".to_string()),
+        signature: Some("<Tom & Jerry>".to_string()),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.title, "Hello world & 1 < 2");
+    assert_eq!(obj.body, "<h2>Intro</h2><p>Text <a>link</a></p>");
+    assert_eq!(obj.comment, Some("Big <em>small</em>".to_string()));
+    assert_eq!(obj.signature, Some("&lt;Tom &amp; Jerry&gt;".to_string()));
+}
+
+#[test]
+fn test_no_html_fails() {
+    let obj = TestPost {
+        title: "".to_string(),
+        summary: Some("<img src=x onerror=alert(1)>".to_string()),
+        body: "".to_string(),
+        comment: None,
+        signature: None,
+    };
+
+    match obj.validate() {
+        Ok(_) => panic!("Was expected to validate no_html properties"),
+        Err(e) => {
+            assert!(e.get_error("summary").unwrap().contains("no_html"));
+        }
+    };
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestQuery {
+    pub search: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub status: Option<String>,
+    pub page_size: Option<u32>,
+    pub sort: Option<String>,
+}
+
+impl Validation for TestQuery {
+    fn rules(&self) -> Vec<Rule<Self>> {
+        vec![
+            rule_required!(status),
+            rule_range!(page_size, Some(1), Some(100)),
+        ]
+    }
+
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_trim!(search),
+            modifier_empty_to_none!(search),
+            modifier_empty_to_none!(tags),
+            modifier_default!(status, "draft"),
+            modifier_default!(page_size, 20),
+            modifier_default_with!(sort, || format!("{}_desc", "created_at")),
+        ]
+    }
+}
+
+#[test]
+fn test_default_modifiers() {
+    let obj = TestQuery {
+        search: Some("   ".to_string()),
+        tags: Some(vec![]),
+        status: None,
+        page_size: None,
+        sort: None,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.search, None);
+    assert_eq!(obj.tags, None);
+    assert_eq!(obj.status, Some("draft".to_string()));
+    assert_eq!(obj.page_size, Some(20));
+    assert_eq!(obj.sort, Some("created_at_desc".to_string()));
+
+    let obj = TestQuery {
+        search: Some(" rust ".to_string()),
+        tags: Some(vec!["web".to_string()]),
+        status: Some("published".to_string()),
+        page_size: Some(50),
+        sort: Some("title_asc".to_string()),
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.search, Some("rust".to_string()));
+    assert_eq!(obj.tags, Some(vec!["web".to_string()]));
+    assert_eq!(obj.status, Some("published".to_string()));
+    assert_eq!(obj.page_size, Some(50));
+    assert_eq!(obj.sort, Some("title_asc".to_string()));
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestEvent {
+    pub user_agent: String,
+    pub message: Option<String>,
+    pub page_size: u32,
+    pub offset: i64,
+    pub volume: Option<f64>,
+    pub ratio: f32,
+}
+
+impl Validation for TestEvent {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_truncate!(user_agent, 10),
+            modifier_truncate!(message, 3, graphemes),
+            modifier_clamp!(page_size, 1, 100),
+            modifier_clamp!(offset, 0, 1000),
+            modifier_clamp!(volume, 0, 1),
+            modifier_round!(volume, 2),
+            modifier_round!(ratio, 1),
+        ]
+    }
+}
+
+#[test]
+fn test_truncate_and_clamp_modifiers() {
+    let obj = TestEvent {
+        user_agent: "Mozilla/5.0 (X11; Linux x86_64)".to_string(),
+        message: Some("🇭🇷🇩🇪🇫🇷🇮🇹".to_string()),
+        page_size: 500,
+        offset: -20,
+        volume: Some(0.6666),
+        ratio: 0.25,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.user_agent, "Mozilla/5.");
+    assert_eq!(obj.message, Some("🇭🇷🇩🇪🇫🇷".to_string()));
+    assert_eq!(obj.page_size, 100);
+    assert_eq!(obj.offset, 0);
+    assert_eq!(obj.volume, Some(0.67));
+    assert_eq!(obj.ratio, 0.3);
+
+    let obj = TestEvent {
+        user_agent: "curl".to_string(),
+        message: None,
+        page_size: 0,
+        offset: 20,
+        volume: Some(1.5),
+        ratio: 2.0,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.user_agent, "curl");
+    assert_eq!(obj.message, None);
+    assert_eq!(obj.page_size, 1);
+    assert_eq!(obj.offset, 20);
+    assert_eq!(obj.volume, Some(1.0));
+    assert_eq!(obj.ratio, 2.0);
+}
+
+#[derive(Clone, Deserialize, Debug)]
+struct TestLargeNumbers {
+    pub id: i64,
+    pub counter: Option<u64>,
+    pub level: u8,
+}
+
+impl Validation for TestLargeNumbers {
+    fn modifiers(&self) -> Vec<Modifier<Self>> {
+        vec![
+            modifier_clamp!(id, 0, 9_007_199_254_740_992_i64),
+            modifier_clamp!(counter, 0, u64::MAX - 1),
+            modifier_clamp!(level, 1.5, 300),
+        ]
+    }
+}
+
+#[test]
+fn test_clamp_modifier_large_integers() {
+    let obj = TestLargeNumbers {
+        id: 9_007_199_254_740_993,
+        counter: Some(u64::MAX),
+        level: 0,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.id, 9_007_199_254_740_992);
+    assert_eq!(obj.counter, Some(u64::MAX - 1));
+    assert_eq!(obj.level, 2);
+
+    let obj = TestLargeNumbers {
+        id: -9_007_199_254_740_993,
+        counter: None,
+        level: 255,
+    };
+
+    let obj = obj.validate().unwrap();
+
+    assert_eq!(obj.id, 0);
+    assert_eq!(obj.counter, None);
+    assert_eq!(obj.level, 255);
+}
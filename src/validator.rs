@@ -2,13 +2,14 @@ use super::error::{ValidationError, ValidationErrors};
 use super::modifier::Modifier;
 use super::rule::Rule;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Mutex;
 
 pub struct Validator<T> {
     item: T,
     rules: Vec<Rule<T>>,
     modifiers: Vec<Modifier<T>>,
+    skip_failed_fields: bool,
     errors: Mutex<HashMap<String, ValidationError>>,
 }
 
@@ -21,6 +22,7 @@ where
             item,
             rules: vec![],
             modifiers: vec![],
+            skip_failed_fields: false,
             errors: Mutex::new(HashMap::<String, ValidationError>::new()),
         }
     }
@@ -39,14 +41,22 @@ where
         self
     }
 
+    /// Skip the rules for fields whose modifier reported an error, so the field only
+    /// reports why its value could not be modified
+    pub fn skip_rules_on_modifier_error(mut self, skip: bool) -> Self {
+        self.skip_failed_fields = skip;
+
+        self
+    }
+
     /// Check if item attribute exists with some rules already defined
     pub fn contains_rule(&self, key: &str) -> bool {
-        matches!(self.rules.iter().position(|r| r.field == key), Some(_))
+        self.rules.iter().any(|r| r.field == key)
     }
 
     /// Check if item attribute exists with some modifiers already defined
     pub fn contains_modifier(&self, key: &str) -> bool {
-        matches!(self.modifiers.iter().position(|r| r.field == key), Some(_))
+        self.modifiers.iter().any(|r| r.field == key)
     }
 
     /// Get instance error for field
@@ -85,11 +95,24 @@ where
 
     /// Run modifiers and validators all at once and return errors, or the item
     pub fn run(mut self) -> Result<T, ValidationErrors> {
+        let mut failed_fields = HashSet::new();
+
         for modifier in &self.modifiers {
-            modifier.handle(&mut self.item);
+            let mut error = self.get_error(&modifier.field);
+
+            modifier.try_handle(&mut self.item, &mut error);
+
+            if error.has_errors() {
+                failed_fields.insert(modifier.field.clone());
+                self.set_error(&modifier.field, error.clone());
+            }
         }
 
         for rule in &self.rules {
+            if self.skip_failed_fields && failed_fields.contains(&rule.field) {
+                continue;
+            }
+
             let mut error = self.get_error(&rule.field);
 
             rule.handle(&self.item, &mut error);
@@ -1,8 +1,39 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::helpers::html::HtmlPolicy;
+use crate::helpers::phone::{PhoneFormat, PhoneOptions};
+use crate::helpers::unicode::NormalizationForm;
+
 pub trait SomeOrStringWrapper {
     fn m_trim(self) -> Self;
     fn m_lowercase(self) -> Self;
     fn m_uppercase(self) -> Self;
     fn m_capitalize(self) -> Self;
+    fn m_normalize_mac(self) -> Self;
+    fn m_phone(self, options: &PhoneOptions, format: PhoneFormat) -> Self;
+    fn m_normalize_credit_card(self) -> Self;
+    fn m_normalize_iban(self) -> Self;
+    fn m_normalize_postal_code(self, country: &str) -> Self;
+    fn m_isbn13(self) -> Self;
+    fn m_collapse_whitespace(self) -> Self;
+    fn m_trim_start(self) -> Self;
+    fn m_trim_end(self) -> Self;
+    fn m_strip_control_characters(self) -> Self;
+    fn m_remove_zero_width(self) -> Self;
+    fn m_normalize_newlines(self) -> Self;
+    fn m_normalize(self, form: NormalizationForm) -> Self;
+    fn m_case_fold(self) -> Self;
+    fn m_title_case(self) -> Self;
+    fn m_slugify(self) -> Self;
+    fn m_snake_case(self) -> Self;
+    fn m_camel_case(self) -> Self;
+    fn m_kebab_case(self) -> Self;
+    fn m_sanitize_html(self, policy: &HtmlPolicy) -> Self;
+    fn m_strip_tags(self) -> Self;
+    fn m_escape_html(self) -> Self;
+    fn m_truncate_chars(self, max: usize) -> Self;
+    fn m_truncate_graphemes(self, max: usize) -> Self;
 }
 
 impl SomeOrStringWrapper for Option<String> {
@@ -20,11 +51,83 @@ impl SomeOrStringWrapper for Option<String> {
             let mut c = v.chars();
 
             c.next()
-                .map(|f| f.to_uppercase().collect::<String>() + c.as_str())
+                .map(|f| f.to_uppercase().collect::<String>() + &c.as_str().to_lowercase())
         } else {
             None
         }
     }
+    fn m_normalize_mac(self) -> Self {
+        self.map(|v| crate::helpers::mac::normalize_mac(&v).unwrap_or(v))
+    }
+    fn m_phone(self, options: &PhoneOptions, format: PhoneFormat) -> Self {
+        self.map(|v| crate::helpers::phone::format_phone_with(&v, options, format).unwrap_or(v))
+    }
+    fn m_normalize_credit_card(self) -> Self {
+        self.map(crate::helpers::card::normalize_credit_card)
+    }
+    fn m_normalize_iban(self) -> Self {
+        self.map(crate::helpers::iban::normalize_iban)
+    }
+    fn m_normalize_postal_code(self, country: &str) -> Self {
+        self.map(|v| crate::helpers::postal_code::normalize_postal_code(country, &v).unwrap_or(v))
+    }
+    fn m_isbn13(self) -> Self {
+        self.map(|v| crate::helpers::product_codes::isbn10_to_isbn13(&v).unwrap_or(v))
+    }
+    fn m_collapse_whitespace(self) -> Self {
+        self.map(crate::helpers::text::collapse_whitespace)
+    }
+    fn m_trim_start(self) -> Self {
+        self.map(|v| v.trim_start().to_string())
+    }
+    fn m_trim_end(self) -> Self {
+        self.map(|v| v.trim_end().to_string())
+    }
+    fn m_strip_control_characters(self) -> Self {
+        self.map(crate::helpers::text::strip_control_characters)
+    }
+    fn m_remove_zero_width(self) -> Self {
+        self.map(crate::helpers::text::remove_zero_width)
+    }
+    fn m_normalize_newlines(self) -> Self {
+        self.map(crate::helpers::text::normalize_newlines)
+    }
+    fn m_normalize(self, form: NormalizationForm) -> Self {
+        self.map(|v| crate::helpers::unicode::normalize(v, form))
+    }
+    fn m_case_fold(self) -> Self {
+        self.map(crate::helpers::unicode::case_fold)
+    }
+    fn m_title_case(self) -> Self {
+        self.map(crate::helpers::case::title_case)
+    }
+    fn m_slugify(self) -> Self {
+        self.map(crate::helpers::slug::slugify)
+    }
+    fn m_snake_case(self) -> Self {
+        self.map(crate::helpers::case::snake_case)
+    }
+    fn m_camel_case(self) -> Self {
+        self.map(crate::helpers::case::camel_case)
+    }
+    fn m_kebab_case(self) -> Self {
+        self.map(crate::helpers::case::kebab_case)
+    }
+    fn m_sanitize_html(self, policy: &HtmlPolicy) -> Self {
+        self.map(|v| crate::helpers::html::sanitize_html(v, policy))
+    }
+    fn m_strip_tags(self) -> Self {
+        self.map(crate::helpers::html::strip_tags)
+    }
+    fn m_escape_html(self) -> Self {
+        self.map(crate::helpers::html::escape_html)
+    }
+    fn m_truncate_chars(self, max: usize) -> Self {
+        self.map(|v| crate::helpers::text::truncate_chars(v, max))
+    }
+    fn m_truncate_graphemes(self, max: usize) -> Self {
+        self.map(|v| crate::helpers::text::truncate_graphemes(v, max))
+    }
 }
 
 impl SomeOrStringWrapper for String {
@@ -42,9 +145,447 @@ impl SomeOrStringWrapper for String {
 
         match c.next() {
             None => self,
-            Some(f) => f.to_uppercase().collect::<String>() + c.as_str(),
+            Some(f) => f.to_uppercase().collect::<String>() + &c.as_str().to_lowercase(),
         }
     }
+    fn m_normalize_mac(self) -> Self {
+        crate::helpers::mac::normalize_mac(&self).unwrap_or(self)
+    }
+    fn m_phone(self, options: &PhoneOptions, format: PhoneFormat) -> Self {
+        crate::helpers::phone::format_phone_with(&self, options, format).unwrap_or(self)
+    }
+    fn m_normalize_credit_card(self) -> Self {
+        crate::helpers::card::normalize_credit_card(self)
+    }
+    fn m_normalize_iban(self) -> Self {
+        crate::helpers::iban::normalize_iban(self)
+    }
+    fn m_normalize_postal_code(self, country: &str) -> Self {
+        crate::helpers::postal_code::normalize_postal_code(country, &self).unwrap_or(self)
+    }
+    fn m_isbn13(self) -> Self {
+        crate::helpers::product_codes::isbn10_to_isbn13(&self).unwrap_or(self)
+    }
+    fn m_collapse_whitespace(self) -> Self {
+        crate::helpers::text::collapse_whitespace(self)
+    }
+    fn m_trim_start(self) -> Self {
+        self.trim_start().to_string()
+    }
+    fn m_trim_end(self) -> Self {
+        self.trim_end().to_string()
+    }
+    fn m_strip_control_characters(self) -> Self {
+        crate::helpers::text::strip_control_characters(self)
+    }
+    fn m_remove_zero_width(self) -> Self {
+        crate::helpers::text::remove_zero_width(self)
+    }
+    fn m_normalize_newlines(self) -> Self {
+        crate::helpers::text::normalize_newlines(self)
+    }
+    fn m_normalize(self, form: NormalizationForm) -> Self {
+        crate::helpers::unicode::normalize(self, form)
+    }
+    fn m_case_fold(self) -> Self {
+        crate::helpers::unicode::case_fold(self)
+    }
+    fn m_title_case(self) -> Self {
+        crate::helpers::case::title_case(self)
+    }
+    fn m_slugify(self) -> Self {
+        crate::helpers::slug::slugify(self)
+    }
+    fn m_snake_case(self) -> Self {
+        crate::helpers::case::snake_case(self)
+    }
+    fn m_camel_case(self) -> Self {
+        crate::helpers::case::camel_case(self)
+    }
+    fn m_kebab_case(self) -> Self {
+        crate::helpers::case::kebab_case(self)
+    }
+    fn m_sanitize_html(self, policy: &HtmlPolicy) -> Self {
+        crate::helpers::html::sanitize_html(self, policy)
+    }
+    fn m_strip_tags(self) -> Self {
+        crate::helpers::html::strip_tags(self)
+    }
+    fn m_escape_html(self) -> Self {
+        crate::helpers::html::escape_html(self)
+    }
+    fn m_truncate_chars(self, max: usize) -> Self {
+        crate::helpers::text::truncate_chars(self, max)
+    }
+    fn m_truncate_graphemes(self, max: usize) -> Self {
+        crate::helpers::text::truncate_graphemes(self, max)
+    }
 }
 
 pub struct SomeOrString<T: SomeOrStringWrapper>(pub T);
+
+/// Typed field that a string field can be coerced into
+pub trait SomeOrValueWrapper {
+    type Value;
+
+    /// Set the parsed value, `None` clears an optional field and leaves the rest as they are
+    fn m_set(&mut self, value: Option<Self::Value>);
+
+    /// Parse the raw value into the field, returns `false` if it could not be parsed.
+    /// Missing and blank values are not parsed.
+    fn m_coerce(&mut self, raw: Option<String>) -> bool
+    where
+        Self::Value: FromStr,
+    {
+        match raw.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            Some(v) => match v.parse::<Self::Value>() {
+                Ok(value) => {
+                    self.m_set(Some(value));
+                    true
+                }
+                Err(_) => {
+                    self.m_set(None);
+                    false
+                }
+            },
+            None => {
+                self.m_set(None);
+                true
+            }
+        }
+    }
+
+    /// Parse the raw value into the boolean field, see [`crate::helpers::coerce::parse_bool`]
+    fn m_coerce_bool(&mut self, raw: Option<String>) -> bool
+    where
+        Self: SomeOrValueWrapper<Value = bool>,
+    {
+        match raw.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            Some(v) => {
+                let value = crate::helpers::coerce::parse_bool(v);
+                self.m_set(value);
+                value.is_some()
+            }
+            None => {
+                self.m_set(None);
+                true
+            }
+        }
+    }
+}
+
+impl<T> SomeOrValueWrapper for Option<T> {
+    type Value = T;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        *self = value;
+    }
+}
+
+impl SomeOrValueWrapper for bool {
+    type Value = bool;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for u8 {
+    type Value = u8;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for u16 {
+    type Value = u16;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for u32 {
+    type Value = u32;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for u64 {
+    type Value = u64;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for usize {
+    type Value = usize;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for i8 {
+    type Value = i8;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for i16 {
+    type Value = i16;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for i32 {
+    type Value = i32;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for i64 {
+    type Value = i64;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for isize {
+    type Value = isize;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for f32 {
+    type Value = f32;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+impl SomeOrValueWrapper for f64 {
+    type Value = f64;
+
+    fn m_set(&mut self, value: Option<Self::Value>) {
+        if let Some(v) = value {
+            *self = v;
+        }
+    }
+}
+
+/// Optional field that is set to `None` when its value is empty
+pub trait SomeOrEmptyWrapper {
+    fn m_empty_to_none(self) -> Self;
+}
+
+impl SomeOrEmptyWrapper for Option<String> {
+    fn m_empty_to_none(self) -> Self {
+        self.filter(|v| !v.is_empty())
+    }
+}
+
+impl<T> SomeOrEmptyWrapper for Option<Vec<T>> {
+    fn m_empty_to_none(self) -> Self {
+        self.filter(|v| !v.is_empty())
+    }
+}
+
+/// Value that fills in an absent `Option<T>` field, `&str` is accepted for `String` fields
+pub trait DefaultValue<T> {
+    fn into_default(self) -> T;
+}
+
+impl<T> DefaultValue<T> for T {
+    fn into_default(self) -> T {
+        self
+    }
+}
+
+impl DefaultValue<String> for &str {
+    fn into_default(self) -> String {
+        self.to_string()
+    }
+}
+
+/// Optional field that is filled in with the default value when it is `None`
+pub trait SomeOrDefaultWrapper<T> {
+    fn m_default<V: DefaultValue<T>>(self, value: V) -> Self;
+    fn m_default_with<V: DefaultValue<T>, F: FnOnce() -> V>(self, default: F) -> Self;
+}
+
+impl<T> SomeOrDefaultWrapper<T> for Option<T> {
+    fn m_default<V: DefaultValue<T>>(self, value: V) -> Self {
+        self.or_else(|| Some(value.into_default()))
+    }
+    fn m_default_with<V: DefaultValue<T>, F: FnOnce() -> V>(self, default: F) -> Self {
+        self.or_else(|| Some(default().into_default()))
+    }
+}
+
+/// Bound of `modifier_clamp!`, integers are compared exactly and float bounds are rounded
+/// towards the range when clamping an integer field
+pub trait ClampBound: Copy {
+    fn to_f64(self) -> f64;
+    fn floor(self) -> i128;
+    fn ceil(self) -> i128;
+}
+
+macro_rules! impl_integer_clamp_bound {
+    ($($type:ty),+) => {
+        $(
+            impl ClampBound for $type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn floor(self) -> i128 {
+                    self as i128
+                }
+                fn ceil(self) -> i128 {
+                    self as i128
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_clamp_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl ClampBound for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    fn floor(self) -> i128 {
+        self.floor() as i128
+    }
+    fn ceil(self) -> i128 {
+        self.ceil() as i128
+    }
+}
+
+impl ClampBound for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+    fn floor(self) -> i128 {
+        self.floor() as i128
+    }
+    fn ceil(self) -> i128 {
+        self.ceil() as i128
+    }
+}
+
+pub trait SomeOrNumberWrapper {
+    fn m_clamp<T: ClampBound, V: ClampBound>(self, min: T, max: V) -> Self;
+    fn m_round(self, decimals: u32) -> Self;
+}
+
+pub struct SomeOrNumber<T: SomeOrNumberWrapper>(pub T);
+
+impl<A> SomeOrNumberWrapper for Option<A>
+where
+    A: SomeOrNumberWrapper,
+{
+    fn m_clamp<T: ClampBound, V: ClampBound>(self, min: T, max: V) -> Self {
+        self.map(|v| v.m_clamp(min, max))
+    }
+    fn m_round(self, decimals: u32) -> Self {
+        self.map(|v| v.m_round(decimals))
+    }
+}
+
+macro_rules! impl_integer_number_wrapper {
+    ($($type:ty),+) => {
+        $(
+            impl SomeOrNumberWrapper for $type {
+                fn m_clamp<T: ClampBound, V: ClampBound>(self, min: T, max: V) -> Self {
+                    // Bound outside of the type range clamps to its minimum or maximum
+                    let saturate = |bound: i128| {
+                        <$type>::try_from(bound).unwrap_or(if bound < 0 {
+                            <$type>::MIN
+                        } else {
+                            <$type>::MAX
+                        })
+                    };
+
+                    if (self as i128) > max.floor() {
+                        saturate(max.floor())
+                    } else if (self as i128) < min.ceil() {
+                        saturate(min.ceil())
+                    } else {
+                        self
+                    }
+                }
+                fn m_round(self, _decimals: u32) -> Self {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_number_wrapper!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl SomeOrNumberWrapper for f32 {
+    fn m_clamp<T: ClampBound, V: ClampBound>(self, min: T, max: V) -> Self {
+        (self as f64).m_clamp(min, max) as f32
+    }
+    fn m_round(self, decimals: u32) -> Self {
+        crate::helpers::number::round_to(self as f64, decimals) as f32
+    }
+}
+
+impl SomeOrNumberWrapper for f64 {
+    fn m_clamp<T: ClampBound, V: ClampBound>(self, min: T, max: V) -> Self {
+        let (min, max) = (min.to_f64(), max.to_f64());
+
+        if self > max {
+            max
+        } else if self < min {
+            min
+        } else {
+            self
+        }
+    }
+    fn m_round(self, decimals: u32) -> Self {
+        crate::helpers::number::round_to(self, decimals)
+    }
+}
@@ -1,27 +1,100 @@
+use crate::helpers::base64::{Base64Error, Base64Options};
+use crate::helpers::card::{CardError, CardType};
+use crate::helpers::geo::BoundingBox;
+use crate::helpers::password::{PasswordError, PasswordPolicy};
+use crate::helpers::phone::{PhoneError, PhoneOptions};
+use crate::helpers::semver::VersionReq;
+use serde::de::DeserializeOwned;
 use std::string::ToString;
 
 pub trait SomeOrStringWrapper {
+    fn value(&self) -> Option<String>;
     fn required(&self) -> bool;
     fn accepted(&self) -> bool;
     fn email(&self) -> bool;
     fn url(&self) -> bool;
     fn phone(&self) -> bool;
+    fn phone_with(&self, options: &PhoneOptions) -> Result<(), PhoneError>;
     fn non_control_character(&self) -> bool;
     fn ip(&self) -> bool;
     fn ip_v4(&self) -> bool;
     fn ip_v6(&self) -> bool;
     fn credit_card(&self) -> bool;
+    fn credit_card_brand(&self, brands: &[CardType]) -> Result<(), CardError>;
+    fn card_expiry(&self) -> bool;
+    fn mac(&self) -> bool;
+    fn mac_eui48(&self) -> bool;
+    fn mac_eui64(&self) -> bool;
+    fn port(&self) -> bool;
+    fn socket_address(&self) -> bool;
     fn rule_contains(&self, needle: String) -> bool;
+    fn rule_starts_with(&self, needle: String, case_insensitive: bool) -> bool;
+    fn rule_ends_with(&self, needle: String, case_insensitive: bool) -> bool;
+    fn rule_not_contains(&self, needle: String, case_insensitive: bool) -> bool;
+    fn rule_contains_any<B: ToString>(&self, needles: Vec<B>, case_insensitive: bool) -> bool;
+    fn rule_contains_all<B: ToString>(&self, needles: Vec<B>, case_insensitive: bool) -> bool;
     fn r#in<B: ToString>(&self, haystack: Vec<B>) -> bool;
+    fn in_with<B: ToString>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool;
+    fn not_in<B: ToString>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool;
     fn length_min(&self, min: usize) -> bool;
     fn length_max(&self, max: usize) -> bool;
     fn length_eq(&self, eq: usize) -> bool;
+    fn iban(&self) -> bool;
+    fn bic(&self) -> bool;
+    fn vat(&self, country: &str) -> bool;
+    fn postal_code(&self, country: &str) -> bool;
+    fn password(&self, policy: &PasswordPolicy) -> Result<(), Vec<PasswordError>>;
+    #[cfg(feature = "country-codes")]
+    fn country_code(&self) -> bool;
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha2(&self) -> bool;
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha3(&self) -> bool;
+    #[cfg(feature = "currency-codes")]
+    fn currency_code(&self) -> bool;
+    #[cfg(feature = "language-tags")]
+    fn language_tag(&self) -> bool;
+    #[cfg(feature = "timezones")]
+    fn timezone(&self) -> bool;
+    fn lat_lng(&self) -> bool;
+    fn geojson_point(&self) -> bool;
+    fn bounding_box(&self, bbox: &BoundingBox) -> bool;
+    fn base64(&self) -> bool;
+    fn base64_with(&self, options: &Base64Options) -> Result<(), Base64Error>;
+    fn hex(&self) -> bool;
+    fn hex_bytes(&self, bytes: usize) -> bool;
+    fn json(&self) -> bool;
+    fn json_as<D: DeserializeOwned>(&self) -> bool;
+    fn jwt(&self) -> bool;
+    fn semver(&self) -> bool;
+    fn semver_req(&self, req: &VersionReq) -> bool;
+    fn slug(&self) -> bool;
+    fn slug_with(&self, separator: char) -> bool;
+    fn css_color(&self) -> bool;
+    fn isbn(&self) -> bool;
+    fn isbn10(&self) -> bool;
+    fn isbn13(&self) -> bool;
+    fn issn(&self) -> bool;
+    fn ean8(&self) -> bool;
+    fn ean13(&self) -> bool;
+    fn upc_a(&self) -> bool;
+    fn gtin14(&self) -> bool;
+    fn gtin(&self) -> bool;
+    fn numeric(&self) -> bool;
+    fn integer_string(&self) -> bool;
+    fn decimal_string(&self) -> bool;
+    fn confusable(&self) -> bool;
+    fn confusable_with<B: ToString>(&self, reserved: &[B]) -> bool;
+    fn no_html(&self) -> bool;
 }
 
 impl<A> SomeOrStringWrapper for &Option<A>
 where
     A: ToString + Clone,
 {
+    fn value(&self) -> Option<String> {
+        self.as_ref().map(|v| v.to_string())
+    }
     fn required(&self) -> bool {
         if self.is_none() {
             true
@@ -61,6 +134,13 @@ where
             false
         }
     }
+    fn phone_with(&self, options: &PhoneOptions) -> Result<(), PhoneError> {
+        if let Some(v) = self {
+            crate::helpers::phone::validate_phone_with(v.to_string(), options)
+        } else {
+            Ok(())
+        }
+    }
     fn non_control_character(&self) -> bool {
         if let Some(v) = self {
             !crate::helpers::non_control_character::validate_non_control_character(v.to_string())
@@ -96,6 +176,55 @@ where
             false
         }
     }
+    fn credit_card_brand(&self, brands: &[CardType]) -> Result<(), CardError> {
+        if let Some(v) = self {
+            crate::helpers::card::validate_credit_card_brand(v.to_string(), brands)
+        } else {
+            Ok(())
+        }
+    }
+    fn card_expiry(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::card::validate_card_expiry_date(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn mac(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::mac::validate_mac(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn mac_eui48(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::mac::validate_mac_eui48(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn mac_eui64(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::mac::validate_mac_eui64(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn port(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::port::validate_port(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn socket_address(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::socket_address::validate_socket_address(v.to_string())
+        } else {
+            false
+        }
+    }
     fn rule_contains(&self, needle: String) -> bool {
         if let Some(v) = self {
             !v.to_string().contains(&needle)
@@ -103,6 +232,58 @@ where
             false
         }
     }
+    fn rule_starts_with(&self, needle: String, case_insensitive: bool) -> bool {
+        if let Some(v) = self {
+            !fold_case(v.to_string(), case_insensitive)
+                .starts_with(&fold_case(needle, case_insensitive))
+        } else {
+            false
+        }
+    }
+    fn rule_ends_with(&self, needle: String, case_insensitive: bool) -> bool {
+        if let Some(v) = self {
+            !fold_case(v.to_string(), case_insensitive)
+                .ends_with(&fold_case(needle, case_insensitive))
+        } else {
+            false
+        }
+    }
+    fn rule_not_contains(&self, needle: String, case_insensitive: bool) -> bool {
+        if let Some(v) = self {
+            fold_case(v.to_string(), case_insensitive)
+                .contains(&fold_case(needle, case_insensitive))
+        } else {
+            false
+        }
+    }
+    fn rule_contains_any<B>(&self, needles: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        if let Some(v) = self {
+            let v = fold_case(v.to_string(), case_insensitive);
+
+            !needles
+                .iter()
+                .any(|n| v.contains(&fold_case(n.to_string(), case_insensitive)))
+        } else {
+            false
+        }
+    }
+    fn rule_contains_all<B>(&self, needles: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        if let Some(v) = self {
+            let v = fold_case(v.to_string(), case_insensitive);
+
+            !needles
+                .iter()
+                .all(|n| v.contains(&fold_case(n.to_string(), case_insensitive)))
+        } else {
+            false
+        }
+    }
     fn r#in<B>(&self, haystack: Vec<B>) -> bool
     where
         B: ToString,
@@ -116,6 +297,26 @@ where
             false
         }
     }
+    fn in_with<B>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        if let Some(v) = self {
+            !contained_in(v.to_string(), haystack, case_insensitive)
+        } else {
+            false
+        }
+    }
+    fn not_in<B>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        if let Some(v) = self {
+            contained_in(v.to_string(), haystack, case_insensitive)
+        } else {
+            false
+        }
+    }
     fn length_min(&self, min: usize) -> bool {
         if let Some(v) = self {
             v.to_string().len() < min
@@ -137,128 +338,937 @@ where
             false
         }
     }
-}
+    fn iban(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::iban::validate_iban(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn bic(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::bic::validate_bic(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn vat(&self, country: &str) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::tax_id::validate_vat(country, v.to_string())
+        } else {
+            false
+        }
+    }
+    fn postal_code(&self, country: &str) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::postal_code::validate_postal_code(country, v.to_string())
+        } else {
+            false
+        }
+    }
+    fn password(&self, policy: &PasswordPolicy) -> Result<(), Vec<PasswordError>> {
+        if let Some(v) = self {
+            crate::helpers::password::validate_password(v.to_string(), policy)
+        } else {
+            Ok(())
+        }
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::country_code::validate_country_code(v.to_string())
+        } else {
+            false
+        }
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha2(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::country_code::validate_country_code_alpha2(v.to_string())
+        } else {
+            false
+        }
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha3(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::country_code::validate_country_code_alpha3(v.to_string())
+        } else {
+            false
+        }
+    }
+    #[cfg(feature = "currency-codes")]
+    fn currency_code(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::currency_code::validate_currency_code(v.to_string())
+        } else {
+            false
+        }
+    }
+    #[cfg(feature = "language-tags")]
+    fn language_tag(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::language_tag::validate_language_tag(v.to_string())
+        } else {
+            false
+        }
+    }
+    #[cfg(feature = "timezones")]
+    fn timezone(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::timezone::validate_timezone(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn lat_lng(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::geo::validate_lat_lng(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn geojson_point(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::geo::validate_geojson_point(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn bounding_box(&self, bbox: &BoundingBox) -> bool {
+        if let Some(v) = self {
+            let v = v.to_string();
 
-impl SomeOrStringWrapper for &String {
-    fn required(&self) -> bool {
-        self.is_empty()
+            match crate::helpers::geo::parse_lat_lng(&v)
+                .or_else(|| crate::helpers::geo::parse_geojson_point(&v))
+            {
+                Some((lat, lng)) => !bbox.contains(lat, lng),
+                None => true,
+            }
+        } else {
+            false
+        }
     }
-    fn accepted(&self) -> bool {
-        *self == "true"
+    fn base64(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::base64::validate_base64(v.to_string())
+        } else {
+            false
+        }
     }
-    fn email(&self) -> bool {
-        crate::helpers::email::validate_email(*self)
+    fn base64_with(&self, options: &Base64Options) -> Result<(), Base64Error> {
+        if let Some(v) = self {
+            crate::helpers::base64::validate_base64_with(v.to_string(), options)
+        } else {
+            Ok(())
+        }
     }
-    fn url(&self) -> bool {
-        crate::helpers::url::validate_url(*self)
+    fn hex(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::hex::validate_hex(v.to_string())
+        } else {
+            false
+        }
     }
-    fn phone(&self) -> bool {
-        crate::helpers::phone::validate_phone(*self)
+    fn hex_bytes(&self, bytes: usize) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::hex::validate_hex_bytes(v.to_string(), bytes)
+        } else {
+            false
+        }
     }
-    fn non_control_character(&self) -> bool {
-        crate::helpers::non_control_character::validate_non_control_character(*self)
+    fn json(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::json::validate_json(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn json_as<D: DeserializeOwned>(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::json::validate_json_as::<D, _>(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn jwt(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::jwt::validate_jwt(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn semver(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::semver::validate_semver(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn semver_req(&self, req: &VersionReq) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::semver::validate_semver_req(v.to_string(), req)
+        } else {
+            false
+        }
+    }
+    fn slug(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::slug::validate_slug(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn slug_with(&self, separator: char) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::slug::validate_slug_with(v.to_string(), separator)
+        } else {
+            false
+        }
+    }
+    fn css_color(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::color::validate_css_color(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn isbn(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_isbn(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn isbn10(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_isbn10(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn isbn13(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_isbn13(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn issn(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_issn(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn ean8(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_ean8(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn ean13(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_ean13(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn upc_a(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_upc_a(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn gtin14(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_gtin14(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn gtin(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::product_codes::validate_gtin(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn numeric(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_numeric(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn integer_string(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_integer_string(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn decimal_string(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_decimal_string(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn confusable(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::unicode::validate_single_script(v.to_string())
+        } else {
+            false
+        }
+    }
+    fn confusable_with<B>(&self, reserved: &[B]) -> bool
+    where
+        B: ToString,
+    {
+        if let Some(v) = self {
+            let v = v.to_string();
+
+            reserved
+                .iter()
+                .any(|r| crate::helpers::unicode::is_confusable_with(v.as_str(), r.to_string()))
+        } else {
+            false
+        }
+    }
+    fn no_html(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::html::validate_no_html(v.to_string())
+        } else {
+            false
+        }
+    }
+}
+
+impl SomeOrStringWrapper for &String {
+    fn value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+    fn required(&self) -> bool {
+        self.is_empty()
+    }
+    fn accepted(&self) -> bool {
+        *self == "true"
+    }
+    fn email(&self) -> bool {
+        crate::helpers::email::validate_email(*self)
+    }
+    fn url(&self) -> bool {
+        crate::helpers::url::validate_url(*self)
+    }
+    fn phone(&self) -> bool {
+        crate::helpers::phone::validate_phone(*self)
+    }
+    fn phone_with(&self, options: &PhoneOptions) -> Result<(), PhoneError> {
+        crate::helpers::phone::validate_phone_with(*self, options)
+    }
+    fn non_control_character(&self) -> bool {
+        crate::helpers::non_control_character::validate_non_control_character(*self)
+    }
+    fn ip(&self) -> bool {
+        crate::helpers::ip::validate_ip(*self)
+    }
+    fn ip_v4(&self) -> bool {
+        crate::helpers::ip::validate_ip_v4(*self)
+    }
+    fn ip_v6(&self) -> bool {
+        crate::helpers::ip::validate_ip_v6(*self)
+    }
+    fn credit_card(&self) -> bool {
+        crate::helpers::card::validate_credit_card(*self)
+    }
+    fn credit_card_brand(&self, brands: &[CardType]) -> Result<(), CardError> {
+        crate::helpers::card::validate_credit_card_brand(*self, brands)
+    }
+    fn card_expiry(&self) -> bool {
+        !crate::helpers::card::validate_card_expiry_date(*self)
+    }
+    fn mac(&self) -> bool {
+        !crate::helpers::mac::validate_mac(*self)
+    }
+    fn mac_eui48(&self) -> bool {
+        !crate::helpers::mac::validate_mac_eui48(*self)
+    }
+    fn mac_eui64(&self) -> bool {
+        !crate::helpers::mac::validate_mac_eui64(*self)
+    }
+    fn port(&self) -> bool {
+        !crate::helpers::port::validate_port(*self)
+    }
+    fn socket_address(&self) -> bool {
+        !crate::helpers::socket_address::validate_socket_address(*self)
+    }
+    fn rule_contains(&self, needle: String) -> bool {
+        self.contains(&needle)
+    }
+    fn rule_starts_with(&self, needle: String, case_insensitive: bool) -> bool {
+        !fold_case(self.to_string(), case_insensitive)
+            .starts_with(&fold_case(needle, case_insensitive))
+    }
+    fn rule_ends_with(&self, needle: String, case_insensitive: bool) -> bool {
+        !fold_case(self.to_string(), case_insensitive)
+            .ends_with(&fold_case(needle, case_insensitive))
+    }
+    fn rule_not_contains(&self, needle: String, case_insensitive: bool) -> bool {
+        fold_case(self.to_string(), case_insensitive).contains(&fold_case(needle, case_insensitive))
+    }
+    fn rule_contains_any<B>(&self, needles: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        let v = fold_case(self.to_string(), case_insensitive);
+
+        !needles
+            .iter()
+            .any(|n| v.contains(&fold_case(n.to_string(), case_insensitive)))
+    }
+    fn rule_contains_all<B>(&self, needles: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        let v = fold_case(self.to_string(), case_insensitive);
+
+        !needles
+            .iter()
+            .all(|n| v.contains(&fold_case(n.to_string(), case_insensitive)))
+    }
+    fn r#in<B>(&self, haystack: Vec<B>) -> bool
+    where
+        B: ToString,
+    {
+        !haystack
+            .iter()
+            .map(|x| x.to_string())
+            .any(|x| x == self.to_string())
+    }
+    fn in_with<B>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        !contained_in(self.to_string(), haystack, case_insensitive)
+    }
+    fn not_in<B>(&self, haystack: Vec<B>, case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        contained_in(self.to_string(), haystack, case_insensitive)
+    }
+    fn length_min(&self, min: usize) -> bool {
+        self.len() < min
+    }
+    fn length_max(&self, max: usize) -> bool {
+        self.len() > max
+    }
+    fn length_eq(&self, eq: usize) -> bool {
+        self.len() != eq
+    }
+    fn iban(&self) -> bool {
+        !crate::helpers::iban::validate_iban(*self)
+    }
+    fn bic(&self) -> bool {
+        !crate::helpers::bic::validate_bic(*self)
+    }
+    fn vat(&self, country: &str) -> bool {
+        !crate::helpers::tax_id::validate_vat(country, *self)
+    }
+    fn postal_code(&self, country: &str) -> bool {
+        !crate::helpers::postal_code::validate_postal_code(country, *self)
+    }
+    fn password(&self, policy: &PasswordPolicy) -> Result<(), Vec<PasswordError>> {
+        crate::helpers::password::validate_password(*self, policy)
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code(&self) -> bool {
+        !crate::helpers::country_code::validate_country_code(*self)
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha2(&self) -> bool {
+        !crate::helpers::country_code::validate_country_code_alpha2(*self)
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha3(&self) -> bool {
+        !crate::helpers::country_code::validate_country_code_alpha3(*self)
+    }
+    #[cfg(feature = "currency-codes")]
+    fn currency_code(&self) -> bool {
+        !crate::helpers::currency_code::validate_currency_code(*self)
+    }
+    #[cfg(feature = "language-tags")]
+    fn language_tag(&self) -> bool {
+        !crate::helpers::language_tag::validate_language_tag(*self)
+    }
+    #[cfg(feature = "timezones")]
+    fn timezone(&self) -> bool {
+        !crate::helpers::timezone::validate_timezone(*self)
+    }
+    fn lat_lng(&self) -> bool {
+        !crate::helpers::geo::validate_lat_lng(*self)
+    }
+    fn geojson_point(&self) -> bool {
+        !crate::helpers::geo::validate_geojson_point(*self)
+    }
+    fn bounding_box(&self, bbox: &BoundingBox) -> bool {
+        match crate::helpers::geo::parse_lat_lng(*self)
+            .or_else(|| crate::helpers::geo::parse_geojson_point(*self))
+        {
+            Some((lat, lng)) => !bbox.contains(lat, lng),
+            None => true,
+        }
+    }
+    fn base64(&self) -> bool {
+        !crate::helpers::base64::validate_base64(*self)
+    }
+    fn base64_with(&self, options: &Base64Options) -> Result<(), Base64Error> {
+        crate::helpers::base64::validate_base64_with(*self, options)
+    }
+    fn hex(&self) -> bool {
+        !crate::helpers::hex::validate_hex(*self)
+    }
+    fn hex_bytes(&self, bytes: usize) -> bool {
+        !crate::helpers::hex::validate_hex_bytes(*self, bytes)
+    }
+    fn json(&self) -> bool {
+        !crate::helpers::json::validate_json(*self)
+    }
+    fn json_as<D: DeserializeOwned>(&self) -> bool {
+        !crate::helpers::json::validate_json_as::<D, _>(*self)
+    }
+    fn jwt(&self) -> bool {
+        !crate::helpers::jwt::validate_jwt(*self)
+    }
+    fn semver(&self) -> bool {
+        !crate::helpers::semver::validate_semver(*self)
+    }
+    fn semver_req(&self, req: &VersionReq) -> bool {
+        !crate::helpers::semver::validate_semver_req(*self, req)
+    }
+    fn slug(&self) -> bool {
+        !crate::helpers::slug::validate_slug(*self)
+    }
+    fn slug_with(&self, separator: char) -> bool {
+        !crate::helpers::slug::validate_slug_with(*self, separator)
+    }
+    fn css_color(&self) -> bool {
+        !crate::helpers::color::validate_css_color(*self)
+    }
+    fn isbn(&self) -> bool {
+        !crate::helpers::product_codes::validate_isbn(*self)
+    }
+    fn isbn10(&self) -> bool {
+        !crate::helpers::product_codes::validate_isbn10(*self)
+    }
+    fn isbn13(&self) -> bool {
+        !crate::helpers::product_codes::validate_isbn13(*self)
+    }
+    fn issn(&self) -> bool {
+        !crate::helpers::product_codes::validate_issn(*self)
+    }
+    fn ean8(&self) -> bool {
+        !crate::helpers::product_codes::validate_ean8(*self)
+    }
+    fn ean13(&self) -> bool {
+        !crate::helpers::product_codes::validate_ean13(*self)
+    }
+    fn upc_a(&self) -> bool {
+        !crate::helpers::product_codes::validate_upc_a(*self)
+    }
+    fn gtin14(&self) -> bool {
+        !crate::helpers::product_codes::validate_gtin14(*self)
+    }
+    fn gtin(&self) -> bool {
+        !crate::helpers::product_codes::validate_gtin(*self)
+    }
+    fn numeric(&self) -> bool {
+        !crate::helpers::number::validate_numeric(*self)
+    }
+    fn integer_string(&self) -> bool {
+        !crate::helpers::number::validate_integer_string(*self)
+    }
+    fn decimal_string(&self) -> bool {
+        !crate::helpers::number::validate_decimal_string(*self)
+    }
+    fn confusable(&self) -> bool {
+        !crate::helpers::unicode::validate_single_script(*self)
+    }
+    fn confusable_with<B>(&self, reserved: &[B]) -> bool
+    where
+        B: ToString,
+    {
+        reserved
+            .iter()
+            .any(|r| crate::helpers::unicode::is_confusable_with(self.as_str(), r.to_string()))
+    }
+    fn no_html(&self) -> bool {
+        !crate::helpers::html::validate_no_html(*self)
+    }
+}
+
+impl SomeOrStringWrapper for &bool {
+    fn value(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+    fn required(&self) -> bool {
+        false
+    }
+    fn accepted(&self) -> bool {
+        *self != &true
+    }
+    fn email(&self) -> bool {
+        false
+    }
+    fn url(&self) -> bool {
+        false
+    }
+    fn phone(&self) -> bool {
+        false
+    }
+    fn phone_with(&self, _options: &PhoneOptions) -> Result<(), PhoneError> {
+        Ok(())
+    }
+    fn non_control_character(&self) -> bool {
+        false
+    }
+    fn ip(&self) -> bool {
+        false
+    }
+    fn ip_v4(&self) -> bool {
+        false
+    }
+    fn ip_v6(&self) -> bool {
+        false
+    }
+    fn credit_card(&self) -> bool {
+        false
+    }
+    fn credit_card_brand(&self, _brands: &[CardType]) -> Result<(), CardError> {
+        Ok(())
+    }
+    fn card_expiry(&self) -> bool {
+        false
+    }
+    fn mac(&self) -> bool {
+        false
+    }
+    fn mac_eui48(&self) -> bool {
+        false
+    }
+    fn mac_eui64(&self) -> bool {
+        false
+    }
+    fn port(&self) -> bool {
+        false
+    }
+    fn socket_address(&self) -> bool {
+        false
+    }
+    fn rule_contains(&self, _needle: String) -> bool {
+        false
+    }
+    fn rule_starts_with(&self, _needle: String, _case_insensitive: bool) -> bool {
+        false
+    }
+    fn rule_ends_with(&self, _needle: String, _case_insensitive: bool) -> bool {
+        false
+    }
+    fn rule_not_contains(&self, _needle: String, _case_insensitive: bool) -> bool {
+        false
+    }
+    fn rule_contains_any<B>(&self, _needles: Vec<B>, _case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        false
+    }
+    fn rule_contains_all<B>(&self, _needles: Vec<B>, _case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        false
+    }
+    fn r#in<B>(&self, _haystack: Vec<B>) -> bool
+    where
+        B: ToString,
+    {
+        false
+    }
+    fn in_with<B>(&self, _haystack: Vec<B>, _case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        false
+    }
+    fn not_in<B>(&self, _haystack: Vec<B>, _case_insensitive: bool) -> bool
+    where
+        B: ToString,
+    {
+        false
+    }
+    fn length_min(&self, _min: usize) -> bool {
+        false
+    }
+    fn length_max(&self, _max: usize) -> bool {
+        false
+    }
+    fn length_eq(&self, _eq: usize) -> bool {
+        false
+    }
+    fn iban(&self) -> bool {
+        false
+    }
+    fn bic(&self) -> bool {
+        false
+    }
+    fn vat(&self, _country: &str) -> bool {
+        false
+    }
+    fn postal_code(&self, _country: &str) -> bool {
+        false
+    }
+    fn password(&self, _policy: &PasswordPolicy) -> Result<(), Vec<PasswordError>> {
+        Ok(())
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code(&self) -> bool {
+        false
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha2(&self) -> bool {
+        false
+    }
+    #[cfg(feature = "country-codes")]
+    fn country_code_alpha3(&self) -> bool {
+        false
+    }
+    #[cfg(feature = "currency-codes")]
+    fn currency_code(&self) -> bool {
+        false
+    }
+    #[cfg(feature = "language-tags")]
+    fn language_tag(&self) -> bool {
+        false
+    }
+    #[cfg(feature = "timezones")]
+    fn timezone(&self) -> bool {
+        false
+    }
+    fn lat_lng(&self) -> bool {
+        false
+    }
+    fn geojson_point(&self) -> bool {
+        false
+    }
+    fn bounding_box(&self, _bbox: &BoundingBox) -> bool {
+        false
+    }
+    fn base64(&self) -> bool {
+        false
+    }
+    fn base64_with(&self, _options: &Base64Options) -> Result<(), Base64Error> {
+        Ok(())
+    }
+    fn hex(&self) -> bool {
+        false
+    }
+    fn hex_bytes(&self, _bytes: usize) -> bool {
+        false
     }
-    fn ip(&self) -> bool {
-        crate::helpers::ip::validate_ip(*self)
+    fn json(&self) -> bool {
+        false
     }
-    fn ip_v4(&self) -> bool {
-        crate::helpers::ip::validate_ip_v4(*self)
+    fn json_as<D: DeserializeOwned>(&self) -> bool {
+        false
     }
-    fn ip_v6(&self) -> bool {
-        crate::helpers::ip::validate_ip_v6(*self)
+    fn jwt(&self) -> bool {
+        false
     }
-    fn credit_card(&self) -> bool {
-        crate::helpers::card::validate_credit_card(*self)
+    fn semver(&self) -> bool {
+        false
     }
-    fn rule_contains(&self, needle: String) -> bool {
-        self.contains(&needle)
+    fn semver_req(&self, _req: &VersionReq) -> bool {
+        false
     }
-    fn r#in<B>(&self, haystack: Vec<B>) -> bool
-    where
-        B: ToString,
-    {
-        !haystack
-            .iter()
-            .map(|x| x.to_string())
-            .any(|x| x == self.to_string())
+    fn slug(&self) -> bool {
+        false
     }
-    fn length_min(&self, min: usize) -> bool {
-        self.len() < min
+    fn slug_with(&self, _separator: char) -> bool {
+        false
     }
-    fn length_max(&self, max: usize) -> bool {
-        self.len() > max
+    fn css_color(&self) -> bool {
+        false
     }
-    fn length_eq(&self, eq: usize) -> bool {
-        self.len() != eq
+    fn isbn(&self) -> bool {
+        false
     }
-}
-
-impl SomeOrStringWrapper for &bool {
-    fn required(&self) -> bool {
+    fn isbn10(&self) -> bool {
         false
     }
-    fn accepted(&self) -> bool {
-        *self != &true
+    fn isbn13(&self) -> bool {
+        false
     }
-    fn email(&self) -> bool {
+    fn issn(&self) -> bool {
         false
     }
-    fn url(&self) -> bool {
+    fn ean8(&self) -> bool {
         false
     }
-    fn phone(&self) -> bool {
+    fn ean13(&self) -> bool {
         false
     }
-    fn non_control_character(&self) -> bool {
+    fn upc_a(&self) -> bool {
         false
     }
-    fn ip(&self) -> bool {
+    fn gtin14(&self) -> bool {
         false
     }
-    fn ip_v4(&self) -> bool {
+    fn gtin(&self) -> bool {
         false
     }
-    fn ip_v6(&self) -> bool {
+    fn numeric(&self) -> bool {
         false
     }
-    fn credit_card(&self) -> bool {
+    fn integer_string(&self) -> bool {
         false
     }
-    fn rule_contains(&self, _needle: String) -> bool {
+    fn decimal_string(&self) -> bool {
         false
     }
-    fn r#in<B>(&self, _haystack: Vec<B>) -> bool
+    fn confusable(&self) -> bool {
+        false
+    }
+    fn confusable_with<B>(&self, _reserved: &[B]) -> bool
     where
         B: ToString,
     {
         false
     }
-    fn length_min(&self, _min: usize) -> bool {
+    fn no_html(&self) -> bool {
         false
     }
-    fn length_max(&self, _max: usize) -> bool {
-        false
+}
+
+pub struct SomeOrString<T: SomeOrStringWrapper>(pub T);
+
+/// Lowercases the value for the case insensitive comparison
+fn fold_case(value: String, case_insensitive: bool) -> String {
+    if case_insensitive {
+        value.to_lowercase()
+    } else {
+        value
     }
-    fn length_eq(&self, _eq: usize) -> bool {
-        false
+}
+
+/// Checks whether the value is one of the haystack values
+fn contained_in<B: ToString>(value: String, haystack: Vec<B>, case_insensitive: bool) -> bool {
+    let value = fold_case(value, case_insensitive);
+
+    haystack
+        .iter()
+        .any(|x| fold_case(x.to_string(), case_insensitive) == value)
+}
+
+pub trait SomeOrCoordinateWrapper {
+    /// `None` when the value is not present, `Some(None)` when it's not a number
+    fn coordinate(&self) -> Option<Option<f64>>;
+
+    fn latitude(&self) -> bool {
+        match self.coordinate() {
+            Some(Some(v)) => !crate::helpers::geo::validate_latitude(v),
+            Some(None) => true,
+            None => false,
+        }
+    }
+    fn longitude(&self) -> bool {
+        match self.coordinate() {
+            Some(Some(v)) => !crate::helpers::geo::validate_longitude(v),
+            Some(None) => true,
+            None => false,
+        }
     }
 }
 
-pub struct SomeOrString<T: SomeOrStringWrapper>(pub T);
+impl SomeOrCoordinateWrapper for &Option<f64> {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        self.map(Some)
+    }
+}
+
+impl SomeOrCoordinateWrapper for &Option<f32> {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        self.map(|v| Some(f64::from(v)))
+    }
+}
+
+impl SomeOrCoordinateWrapper for &Option<String> {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        self.as_ref().map(crate::helpers::geo::parse_coordinate)
+    }
+}
+
+impl SomeOrCoordinateWrapper for &f64 {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        Some(Some(**self))
+    }
+}
+
+impl SomeOrCoordinateWrapper for &f32 {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        Some(Some(f64::from(**self)))
+    }
+}
+
+impl SomeOrCoordinateWrapper for &String {
+    fn coordinate(&self) -> Option<Option<f64>> {
+        Some(crate::helpers::geo::parse_coordinate(*self))
+    }
+}
+
+pub struct SomeOrCoordinate<T: SomeOrCoordinateWrapper>(pub T);
 
 pub trait SomeOrNumberWrapper {
     fn range<T: Into<f64>, V: Into<f64>>(&self, min: Option<T>, max: Option<V>) -> bool;
+    fn positive(&self) -> bool;
+    fn non_negative(&self) -> bool;
+    fn negative(&self) -> bool;
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool;
+    fn decimal_places(&self, max: usize) -> bool;
+    fn finite(&self) -> bool;
+    fn integer(&self) -> bool;
+    fn numeric(&self) -> bool {
+        false
+    }
+}
+
+/// Value that a number rule can be checked against
+pub trait ToNumber {
+    fn to_number(&self) -> f64;
+}
+
+impl<T> ToNumber for T
+where
+    T: Into<f64> + Clone,
+{
+    fn to_number(&self) -> f64 {
+        self.clone().into()
+    }
 }
 
-pub struct SomeOrNumber<T: SomeOrNumberWrapper>(pub T);
+pub struct SomeOrNumber<T>(pub T);
 
 impl<A> SomeOrNumberWrapper for &Option<A>
 where
-    A: Into<f64> + PartialOrd + Clone,
+    A: ToNumber,
 {
     fn range<T: Into<f64>, V: Into<f64>>(&self, min: Option<T>, max: Option<V>) -> bool {
         if let Some(v) = self {
-            let value: f64 = v.clone().into();
+            let value = v.to_number();
+
             if let Some(max) = max {
                 if value > max.into() {
                     return true;
@@ -276,6 +1286,58 @@ where
             false
         }
     }
+    fn positive(&self) -> bool {
+        if let Some(v) = self {
+            let value = v.to_number();
+            value.is_nan() || value <= 0.0
+        } else {
+            false
+        }
+    }
+    fn non_negative(&self) -> bool {
+        if let Some(v) = self {
+            let value = v.to_number();
+            value.is_nan() || value < 0.0
+        } else {
+            false
+        }
+    }
+    fn negative(&self) -> bool {
+        if let Some(v) = self {
+            let value = v.to_number();
+            value.is_nan() || value >= 0.0
+        } else {
+            false
+        }
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_multiple_of(v.to_number(), step.into())
+        } else {
+            false
+        }
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_decimal_places(v.to_number(), max)
+        } else {
+            false
+        }
+    }
+    fn finite(&self) -> bool {
+        if let Some(v) = self {
+            !v.to_number().is_finite()
+        } else {
+            false
+        }
+    }
+    fn integer(&self) -> bool {
+        if let Some(v) = self {
+            !crate::helpers::number::validate_integer(v.to_number())
+        } else {
+            false
+        }
+    }
 }
 
 impl SomeOrNumberWrapper for usize {
@@ -294,6 +1356,27 @@ impl SomeOrNumberWrapper for usize {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for u8 {
@@ -312,6 +1395,27 @@ impl SomeOrNumberWrapper for u8 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for u16 {
@@ -330,6 +1434,27 @@ impl SomeOrNumberWrapper for u16 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for u32 {
@@ -348,6 +1473,27 @@ impl SomeOrNumberWrapper for u32 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for u64 {
@@ -366,6 +1512,27 @@ impl SomeOrNumberWrapper for u64 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for isize {
@@ -384,6 +1551,27 @@ impl SomeOrNumberWrapper for isize {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for i8 {
@@ -402,6 +1590,27 @@ impl SomeOrNumberWrapper for i8 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for i16 {
@@ -420,6 +1629,27 @@ impl SomeOrNumberWrapper for i16 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for i32 {
@@ -438,6 +1668,27 @@ impl SomeOrNumberWrapper for i32 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for i64 {
@@ -456,23 +1707,53 @@ impl SomeOrNumberWrapper for i64 {
 
         false
     }
+    fn positive(&self) -> bool {
+        (*self as f64) <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        (*self as f64) < 0.0
+    }
+    fn negative(&self) -> bool {
+        (*self as f64) >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self as f64, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self as f64, max)
+    }
+    fn finite(&self) -> bool {
+        !(*self as f64).is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self as f64)
+    }
 }
 
 impl SomeOrNumberWrapper for f32 {
     fn range<T: Into<f64>, V: Into<f64>>(&self, min: Option<T>, max: Option<V>) -> bool {
-        if let Some(max) = max {
-            if (*self as f64) > max.into() {
-                return true;
-            }
-        }
-
-        if let Some(min) = min {
-            if (*self as f64) < min.into() {
-                return true;
-            }
-        }
-
-        false
+        crate::helpers::number::f32_to_f64(*self).range(min, max)
+    }
+    fn positive(&self) -> bool {
+        crate::helpers::number::f32_to_f64(*self).positive()
+    }
+    fn non_negative(&self) -> bool {
+        crate::helpers::number::f32_to_f64(*self).non_negative()
+    }
+    fn negative(&self) -> bool {
+        crate::helpers::number::f32_to_f64(*self).negative()
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        crate::helpers::number::f32_to_f64(*self).multiple_of(step)
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        crate::helpers::number::f32_to_f64(*self).decimal_places(max)
+    }
+    fn finite(&self) -> bool {
+        crate::helpers::number::f32_to_f64(*self).finite()
+    }
+    fn integer(&self) -> bool {
+        crate::helpers::number::f32_to_f64(*self).integer()
     }
 }
 
@@ -492,4 +1773,101 @@ impl SomeOrNumberWrapper for f64 {
 
         false
     }
+    fn positive(&self) -> bool {
+        self.is_nan() || *self <= 0.0
+    }
+    fn non_negative(&self) -> bool {
+        self.is_nan() || *self < 0.0
+    }
+    fn negative(&self) -> bool {
+        self.is_nan() || *self >= 0.0
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        !crate::helpers::number::validate_multiple_of(*self, step.into())
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        !crate::helpers::number::validate_decimal_places(*self, max)
+    }
+    fn finite(&self) -> bool {
+        !self.is_finite()
+    }
+    fn integer(&self) -> bool {
+        !crate::helpers::number::validate_integer(*self)
+    }
+}
+
+impl SomeOrNumberWrapper for String {
+    fn range<T: Into<f64>, V: Into<f64>>(&self, min: Option<T>, max: Option<V>) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.range(min, max))
+    }
+    fn positive(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.positive())
+    }
+    fn non_negative(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.non_negative())
+    }
+    fn negative(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.negative())
+    }
+    fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.multiple_of(step))
+    }
+    fn decimal_places(&self, max: usize) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.decimal_places(max))
+    }
+    fn finite(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.finite())
+    }
+    fn integer(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).map_or(true, |v| v.integer())
+    }
+    fn numeric(&self) -> bool {
+        crate::helpers::number::parse_number(self.as_str()).is_none()
+    }
+}
+
+/// Optional values that are not a `ToNumber`, or that have their own conversion to `f64`.
+/// 64 bit and pointer sized integers are not `Into<f64>`, and an explicit `ToNumber` impl
+/// for them would conflict with the blanket impl. Method resolution picks these before
+/// the `&Option<A: ToNumber>` impl.
+macro_rules! impl_option_number_wrapper {
+    ($($type:ty),+) => {
+        $(
+            impl SomeOrNumberWrapper for Option<$type> {
+                fn range<T: Into<f64>, V: Into<f64>>(
+                    &self,
+                    min: Option<T>,
+                    max: Option<V>,
+                ) -> bool {
+                    self.as_ref().is_some_and(|v| v.range(min, max))
+                }
+                fn positive(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.positive())
+                }
+                fn non_negative(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.non_negative())
+                }
+                fn negative(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.negative())
+                }
+                fn multiple_of<T: Into<f64>>(&self, step: T) -> bool {
+                    self.as_ref().is_some_and(|v| v.multiple_of(step))
+                }
+                fn decimal_places(&self, max: usize) -> bool {
+                    self.as_ref().is_some_and(|v| v.decimal_places(max))
+                }
+                fn finite(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.finite())
+                }
+                fn integer(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.integer())
+                }
+                fn numeric(&self) -> bool {
+                    self.as_ref().is_some_and(|v| v.numeric())
+                }
+            }
+        )+
+    };
 }
+
+impl_option_number_wrapper!(String, f32, u64, i64, usize, isize);